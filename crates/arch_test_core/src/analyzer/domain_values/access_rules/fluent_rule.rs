@@ -0,0 +1,277 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+use itertools::Itertools;
+
+use crate::analyzer::domain_values::access_rules::{
+    MayNotAccess, MayNotBeAccessedBy, MayOnlyAccess, MayOnlyBeAccessedBy,
+};
+use crate::analyzer::domain_values::{quoted_list, ParameterValue, RuleDescriptor, ViolatedRule};
+
+/// # Access rule defined by the fluent rule DSL
+/// It is created by `modules().that().reside_in(..).should()` followed by conditions that are chained with `and()`.
+/// Alternative conditions are chained with `or()`: A selected module only violates the rule if it violates every alternative.
+/// Each condition compiles down to an access rule per selected layer, e.g. `only_be_accessed_by` to `MayOnlyBeAccessedBy`.
+/// A reason that explains the rule can be added using `because` and is carried into its violations.
+/// A rule without selected layers or with an alternative without conditions is rejected by `Architecture::validate_access_rules`.
+///
+/// Example:
+/// ```ignore
+/// modules().that().reside_in("services").or().reside_in("adapters")
+///     .should().only_be_accessed_by(["tests", "materials"])
+///     .and().not_access(["tests"])
+///     .or().only_be_accessed_by(["tests"])
+///     .because("services are orchestrated by materials");
+/// ```
+#[derive(Debug, Clone)]
+pub struct FluentRule {
+    layers: Vec<String>,
+    condition_groups: Vec<ConditionGroup>,
+    reason: Option<String>,
+}
+
+/// # Conditions of a `FluentRule` that are chained with `and()`
+/// The compiled access rules of the conditions are checked for every selected layer.
+#[derive(Debug, Clone, Default)]
+pub struct ConditionGroup {
+    conditions: Vec<String>,
+    may_only_access: Vec<MayOnlyAccess>,
+    may_not_access: Vec<MayNotAccess>,
+    may_only_be_accessed_by: Vec<MayOnlyBeAccessedBy>,
+    may_not_be_accessed_by: Vec<MayNotBeAccessedBy>,
+}
+
+impl FluentRule {
+    pub fn new(layers: Vec<String>) -> Self {
+        FluentRule {
+            layers,
+            condition_groups: vec![ConditionGroup::default()],
+            reason: None,
+        }
+    }
+
+    /// The selected modules may only access the `accessed` layers
+    pub fn only_access<I>(mut self, accessed: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let accessed = to_layers(accessed);
+        let condition_group = self.condition_groups.last_mut().unwrap();
+        condition_group.conditions.push(format!(
+            "only access {}",
+            quoted_list(accessed.iter().sorted())
+        ));
+        for layer in self.layers.iter() {
+            condition_group.may_only_access.push(MayOnlyAccess::new(
+                layer.clone(),
+                accessed.clone(),
+                false,
+            ));
+        }
+        self
+    }
+
+    /// The selected modules may not access the `accessed` layers
+    pub fn not_access<I>(mut self, accessed: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let accessed = to_layers(accessed);
+        let condition_group = self.condition_groups.last_mut().unwrap();
+        condition_group.conditions.push(format!(
+            "not access {}",
+            quoted_list(accessed.iter().sorted())
+        ));
+        for layer in self.layers.iter() {
+            condition_group.may_not_access.push(MayNotAccess::new(
+                layer.clone(),
+                accessed.clone(),
+                false,
+            ));
+        }
+        self
+    }
+
+    /// The selected modules may only be accessed by the `accessors` layers
+    pub fn only_be_accessed_by<I>(mut self, accessors: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let accessors = to_layers(accessors);
+        let condition_group = self.condition_groups.last_mut().unwrap();
+        condition_group.conditions.push(format!(
+            "only be accessed by {}",
+            quoted_list(accessors.iter().sorted())
+        ));
+        for layer in self.layers.iter() {
+            condition_group
+                .may_only_be_accessed_by
+                .push(MayOnlyBeAccessedBy::new(
+                    layer.clone(),
+                    accessors.clone(),
+                    false,
+                ));
+        }
+        self
+    }
+
+    /// The selected modules may not be accessed by the `accessors` layers
+    pub fn not_be_accessed_by<I>(mut self, accessors: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let accessors = to_layers(accessors);
+        let condition_group = self.condition_groups.last_mut().unwrap();
+        condition_group.conditions.push(format!(
+            "not be accessed by {}",
+            quoted_list(accessors.iter().sorted())
+        ));
+        for layer in self.layers.iter() {
+            condition_group
+                .may_not_be_accessed_by
+                .push(MayNotBeAccessedBy::new(
+                    layer.clone(),
+                    accessors.clone(),
+                    false,
+                ));
+        }
+        self
+    }
+
+    /// Chains another condition
+    pub fn and(self) -> Self {
+        self
+    }
+
+    /// Chains an alternative to the conditions so far
+    pub fn or(mut self) -> Self {
+        self.condition_groups.push(ConditionGroup::default());
+        self
+    }
+
+    /// Explains why the rule exists
+    pub fn because(mut self, reason: &str) -> Self {
+        self.reason = Some(reason.to_owned());
+        self
+    }
+
+    pub fn reason(&self) -> Option<&String> {
+        self.reason.as_ref()
+    }
+
+    pub fn layers(&self) -> &Vec<String> {
+        &self.layers
+    }
+
+    pub fn condition_groups(&self) -> &Vec<ConditionGroup> {
+        &self.condition_groups
+    }
+
+    /// Describes the rule in the words of the DSL, e.g. "modules that reside in `services` should not access `tests`"
+    pub fn description(&self) -> String {
+        format!(
+            "modules that reside in {} should {}",
+            self.layers
+                .iter()
+                .map(|layer| format!("`{}`", layer))
+                .join(" or "),
+            self.condition_groups
+                .iter()
+                .map(|condition_group| condition_group.description())
+                .join(" or ")
+        )
+    }
+
+    /// Lets the layer selectors of the compiled rules match the crates of the external layers they name, see `LayerSelector::resolve_external_layers`.
+    pub fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        for condition_group in self.condition_groups.iter_mut() {
+            condition_group.resolve_external_layers(external_layers);
+        }
+    }
+}
+
+impl ConditionGroup {
+    pub fn conditions(&self) -> &Vec<String> {
+        &self.conditions
+    }
+
+    /// Describes the conditions in the words of the DSL, e.g. "not access `tests` and only be accessed by `materials`"
+    pub fn description(&self) -> String {
+        self.conditions.join(" and ")
+    }
+
+    pub fn may_only_access(&self) -> &Vec<MayOnlyAccess> {
+        &self.may_only_access
+    }
+
+    pub fn may_not_access(&self) -> &Vec<MayNotAccess> {
+        &self.may_not_access
+    }
+
+    pub fn may_only_be_accessed_by(&self) -> &Vec<MayOnlyBeAccessedBy> {
+        &self.may_only_be_accessed_by
+    }
+
+    pub fn may_not_be_accessed_by(&self) -> &Vec<MayNotBeAccessedBy> {
+        &self.may_not_be_accessed_by
+    }
+
+    fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        for access_rule in self.may_only_access.iter_mut() {
+            access_rule.resolve_external_layers(external_layers);
+        }
+        for access_rule in self.may_not_access.iter_mut() {
+            access_rule.resolve_external_layers(external_layers);
+        }
+        for access_rule in self.may_only_be_accessed_by.iter_mut() {
+            access_rule.resolve_external_layers(external_layers);
+        }
+        for access_rule in self.may_not_be_accessed_by.iter_mut() {
+            access_rule.resolve_external_layers(external_layers);
+        }
+    }
+}
+
+fn to_layers<I>(layers: I) -> HashSet<String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    layers
+        .into_iter()
+        .map(|layer| layer.as_ref().to_owned())
+        .collect()
+}
+
+impl Display for FluentRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl ViolatedRule for FluentRule {
+    fn name(&self) -> &'static str {
+        "FluentRule"
+    }
+
+    fn descriptor(&self) -> RuleDescriptor {
+        let mut descriptor = RuleDescriptor::new(self.name())
+            .with_parameter("layers", ParameterValue::list(self.layers.iter()))
+            .with_parameter(
+                "conditions",
+                ParameterValue::list(
+                    self.condition_groups
+                        .iter()
+                        .map(|condition_group| condition_group.description()),
+                ),
+            );
+        if let Some(reason) = self.reason.as_ref() {
+            descriptor = descriptor.with_parameter("reason", ParameterValue::text(reason));
+        }
+        descriptor
+    }
+}
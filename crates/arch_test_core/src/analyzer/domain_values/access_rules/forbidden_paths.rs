@@ -0,0 +1,87 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+use itertools::Itertools;
+
+use crate::analyzer::domain_values::{
+    matches_path_pattern, quoted_list, LayerSelector, ParameterValue, RuleDescriptor, ViolatedRule,
+};
+
+/// # Forbidden paths in a layer
+/// This access rule states that the `layer` may neither import nor use any of the `paths`, e.g. `std::process::exit` or `println!`.
+/// A path pattern matches every path that starts with it, e.g. `std::fs` matches `std::fs::read_to_string`,
+/// or that ends with it, e.g. `Instant::now` matches `std::time::Instant::now`.
+/// `*` matches a single segment (or a part of it) and `**` any number of segments.
+/// Macro calls are matched without the trailing `!`, i.e. `dbg!` also matches a function called `dbg`.
+///
+/// Example:
+/// ```ignore
+/// ForbiddenPaths::new("domain".to_owned(), hash_set!["std::fs".to_owned(), "println!".to_owned(), "Instant::now".to_owned()]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ForbiddenPaths {
+    layer: LayerSelector,
+    paths: HashSet<String>,
+}
+
+impl ForbiddenPaths {
+    pub fn new(layer: String, paths: HashSet<String>) -> Self {
+        ForbiddenPaths {
+            layer: LayerSelector::from_layer_name(&layer),
+            paths,
+        }
+    }
+
+    pub fn layer(&self) -> &LayerSelector {
+        &self.layer
+    }
+
+    pub fn paths(&self) -> &HashSet<String> {
+        &self.paths
+    }
+
+    /// Returns the alphabetically first pattern that matches the `path`
+    pub fn find_matching_pattern(&self, path: &str) -> Option<&String> {
+        let segments: Vec<&str> = path.trim_start_matches("::").split("::").collect();
+        self.paths.iter().sorted().find(|pattern| {
+            let pattern: Vec<&str> = pattern.trim_end_matches('!').split("::").collect();
+            let prefix: Vec<&str> = pattern
+                .iter()
+                .cloned()
+                .chain(std::iter::once("**"))
+                .collect();
+            let suffix: Vec<&str> = std::iter::once("**")
+                .chain(pattern.iter().cloned())
+                .collect();
+            matches_path_pattern(&prefix, &segments) || matches_path_pattern(&suffix, &segments)
+        })
+    }
+
+    /// Lets the layer selectors of this rule match the crates of the external layers they name, see `LayerSelector::resolve_external_layers`.
+    pub fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        self.layer.resolve_external_layers(external_layers);
+    }
+}
+
+impl Display for ForbiddenPaths {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "modules in `{}` may not use {}",
+            self.layer,
+            quoted_list(self.paths.iter().sorted())
+        )
+    }
+}
+
+impl ViolatedRule for ForbiddenPaths {
+    fn name(&self) -> &'static str {
+        "ForbiddenPaths"
+    }
+
+    fn descriptor(&self) -> RuleDescriptor {
+        RuleDescriptor::new(self.name())
+            .with_parameter("layer", ParameterValue::text(&self.layer))
+            .with_parameter("paths", ParameterValue::sorted_list(self.paths.iter()))
+    }
+}
@@ -0,0 +1,67 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+use crate::analyzer::domain_values::{
+    quoted_list, ItemSelector, ParameterValue, RuleDescriptor, ViolatedRule,
+};
+
+/// # `Accessor` item may not access `accessed` items relation
+/// This access rule relation states that the items selected by `accessor` may not access the items selected by `accessed`.
+/// An item accesses everything that is used within its body, e.g. the fields of a struct or the body of a function.
+/// The `impl` blocks of a type are part of the type.
+/// Uses outside of any item, e.g. in a `const`, are not covered by this rule.
+///
+/// Example:
+/// ```ignore
+/// ItemMayNotAccess::new(ItemSelector::new("Order"), vec![ItemSelector::new("PaymentGateway")])
+/// ```
+#[derive(Debug, Clone)]
+pub struct ItemMayNotAccess {
+    accessor: ItemSelector,
+    accessed: Vec<ItemSelector>,
+}
+
+impl ItemMayNotAccess {
+    pub fn new(accessor: ItemSelector, accessed: Vec<ItemSelector>) -> Self {
+        ItemMayNotAccess { accessor, accessed }
+    }
+
+    pub fn accessor(&self) -> &ItemSelector {
+        &self.accessor
+    }
+
+    pub fn accessed(&self) -> &Vec<ItemSelector> {
+        &self.accessed
+    }
+
+    /// Lets the layer selectors of the item selectors match the crates of the external layers they name, see `LayerSelector::resolve_external_layers`.
+    pub fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        self.accessor.resolve_external_layers(external_layers);
+        for selector in self.accessed.iter_mut() {
+            selector.resolve_external_layers(external_layers);
+        }
+    }
+}
+
+impl Display for ItemMayNotAccess {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "items matching `{}` may not access items matching {}",
+            self.accessor,
+            quoted_list(self.accessed.iter())
+        )
+    }
+}
+
+impl ViolatedRule for ItemMayNotAccess {
+    fn name(&self) -> &'static str {
+        "ItemMayNotAccess"
+    }
+
+    fn descriptor(&self) -> RuleDescriptor {
+        RuleDescriptor::new(self.name())
+            .with_parameter("accessor", ParameterValue::text(&self.accessor))
+            .with_parameter("accessed", ParameterValue::list(self.accessed.iter()))
+    }
+}
@@ -0,0 +1,74 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+use crate::analyzer::domain_values::{
+    quoted_list, ItemSelector, ParameterValue, RuleDescriptor, ViolatedRule,
+};
+
+/// # `Accessed` items may only be accessed by `accessors` items relation
+/// This access rule relation states that the items selected by `accessed` may only be accessed by the items selected by `accessors`.
+/// An item accesses everything that is used within its body, e.g. the fields of a struct or the body of a function.
+/// The `impl` blocks of a type are part of the type.
+/// Uses outside of any item, e.g. in a `const`, are not covered by this rule.
+///
+/// Example:
+/// ```ignore
+/// // Only repositories may use sqlx
+/// ItemMayOnlyBeAccessedBy::new(
+///     ItemSelector::new("*").with_layer("sqlx"),
+///     vec![ItemSelector::new("*Repository").with_kind(ObjectType::Struct)],
+/// )
+/// ```
+#[derive(Debug, Clone)]
+pub struct ItemMayOnlyBeAccessedBy {
+    accessed: ItemSelector,
+    accessors: Vec<ItemSelector>,
+}
+
+impl ItemMayOnlyBeAccessedBy {
+    pub fn new(accessed: ItemSelector, accessors: Vec<ItemSelector>) -> Self {
+        ItemMayOnlyBeAccessedBy {
+            accessed,
+            accessors,
+        }
+    }
+
+    pub fn accessed(&self) -> &ItemSelector {
+        &self.accessed
+    }
+
+    pub fn accessors(&self) -> &Vec<ItemSelector> {
+        &self.accessors
+    }
+
+    /// Lets the layer selectors of the item selectors match the crates of the external layers they name, see `LayerSelector::resolve_external_layers`.
+    pub fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        self.accessed.resolve_external_layers(external_layers);
+        for selector in self.accessors.iter_mut() {
+            selector.resolve_external_layers(external_layers);
+        }
+    }
+}
+
+impl Display for ItemMayOnlyBeAccessedBy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "items matching `{}` may only be accessed by items matching {}",
+            self.accessed,
+            quoted_list(self.accessors.iter())
+        )
+    }
+}
+
+impl ViolatedRule for ItemMayOnlyBeAccessedBy {
+    fn name(&self) -> &'static str {
+        "ItemMayOnlyBeAccessedBy"
+    }
+
+    fn descriptor(&self) -> RuleDescriptor {
+        RuleDescriptor::new(self.name())
+            .with_parameter("accessed", ParameterValue::text(&self.accessed))
+            .with_parameter("accessors", ParameterValue::list(self.accessors.iter()))
+    }
+}
@@ -0,0 +1,72 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+use crate::analyzer::domain_values::{
+    quoted_list, LayerSelector, ParameterValue, RuleDescriptor, ViolatedRule,
+};
+
+/// # Layered architecture
+/// This access rule takes an ordered list of layers, starting with the top most layer.
+/// Each layer may only access the layers below it. Accesses within the same layer are allowed.
+/// If `strict` is `true`, a layer may only access the layer directly below it.
+/// Modules that are not part of any of the layers are not considered.
+/// If a module matches several layers, the first matching layer in the list is used.
+#[derive(Debug, Clone)]
+pub struct LayeredArchitecture {
+    layers: Vec<LayerSelector>,
+    strict: bool,
+}
+
+impl LayeredArchitecture {
+    pub fn new(layers: Vec<String>, strict: bool) -> Self {
+        LayeredArchitecture {
+            layers: layers
+                .iter()
+                .map(|layer| LayerSelector::from_layer_name(layer))
+                .collect(),
+            strict,
+        }
+    }
+
+    pub fn layers(&self) -> &Vec<LayerSelector> {
+        &self.layers
+    }
+
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Lets the layer selectors of this rule match the crates of the external layers they name, see `LayerSelector::resolve_external_layers`.
+    pub fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        for selector in self.layers.iter_mut() {
+            selector.resolve_external_layers(external_layers);
+        }
+    }
+}
+
+impl Display for LayeredArchitecture {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the layers {} may only access {}",
+            quoted_list(self.layers.iter()),
+            if self.strict {
+                "the layer directly below them"
+            } else {
+                "the layers below them"
+            }
+        )
+    }
+}
+
+impl ViolatedRule for LayeredArchitecture {
+    fn name(&self) -> &'static str {
+        "LayeredArchitecture"
+    }
+
+    fn descriptor(&self) -> RuleDescriptor {
+        RuleDescriptor::new(self.name())
+            .with_parameter("layers", ParameterValue::list(self.layers.iter()))
+            .with_parameter("strict", ParameterValue::Bool(self.strict))
+    }
+}
@@ -1,34 +1,92 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+use itertools::Itertools;
+
+use crate::analyzer::domain_values::{
+    quoted_list, LayerSelector, ParameterValue, RuleDescriptor, ViolatedRule,
+};
 
 /// # `Accessor` may not access `accessed` relation
 /// This access rule relation states that the `accessor` layer may not access the specified `accessed` layers.
-/// As layer name it attempts to match either the module name or the parent module name, which is the directory the files were placed in.
+/// The layers are parsed as `LayerSelector`s, i.e. a module name matches the module itself or any parent module, which is the directory the files were placed in.
+/// Fully qualified path patterns like `crate::**::services` and `regex:` selectors are supported as well.
 /// If `when_same_parent` is `true`, the access rule is only applied within the same scope of modules that share the same parent.
 #[derive(Debug, Clone)]
 pub struct MayNotAccess {
-    accessor: String,
-    accessed: HashSet<String>,
+    accessor: LayerSelector,
+    accessed: HashSet<LayerSelector>,
     when_same_parent: bool,
 }
 
 impl MayNotAccess {
     pub fn new(accessor: String, accessed_layers: HashSet<String>, when_same_parent: bool) -> Self {
         MayNotAccess {
-            accessor,
-            accessed: accessed_layers,
+            accessor: LayerSelector::from_layer_name(&accessor),
+            accessed: accessed_layers
+                .iter()
+                .map(|layer| LayerSelector::from_layer_name(layer))
+                .collect(),
             when_same_parent,
         }
     }
 
-    pub fn accessor(&self) -> &String {
+    pub fn accessor(&self) -> &LayerSelector {
         &self.accessor
     }
 
-    pub fn accessed(&self) -> &HashSet<String> {
+    pub fn accessed(&self) -> &HashSet<LayerSelector> {
         &self.accessed
     }
 
     pub fn when_same_parent(&self) -> bool {
         self.when_same_parent
     }
+
+    /// Lets the layer selectors of this rule match the crates of the external layers they name, see `LayerSelector::resolve_external_layers`.
+    pub fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        self.accessor.resolve_external_layers(external_layers);
+        self.accessed = self
+            .accessed
+            .drain()
+            .map(|mut selector| {
+                selector.resolve_external_layers(external_layers);
+                selector
+            })
+            .collect();
+    }
+}
+
+impl Display for MayNotAccess {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "modules in `{}` may not access {}",
+            self.accessor,
+            quoted_list(self.accessed.iter().map(LayerSelector::as_str).sorted())
+        )?;
+        if self.when_same_parent {
+            write!(f, " (within the same parent)")?;
+        }
+        Ok(())
+    }
+}
+
+impl ViolatedRule for MayNotAccess {
+    fn name(&self) -> &'static str {
+        "MayNotAccess"
+    }
+
+    fn descriptor(&self) -> RuleDescriptor {
+        RuleDescriptor::new(self.name())
+            .with_parameter("accessor", ParameterValue::text(&self.accessor))
+            .with_parameter(
+                "accessed",
+                ParameterValue::sorted_list(self.accessed.iter()),
+            )
+            .with_parameter(
+                "when_same_parent",
+                ParameterValue::Bool(self.when_same_parent),
+            )
+    }
 }
@@ -1,34 +1,92 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+use itertools::Itertools;
+
+use crate::analyzer::domain_values::{
+    quoted_list, LayerSelector, ParameterValue, RuleDescriptor, ViolatedRule,
+};
 
 /// # `Accessed` may not be accessed by `accessors` relation
 /// This access rule relation states that the `accessors` layers may not access the specified `accessed` layer.
-/// As layer name it attempts to match either the module name or the parent module name, which is the directory the files were placed in.
+/// The layers are parsed as `LayerSelector`s, i.e. a module name matches the module itself or any parent module, which is the directory the files were placed in.
+/// Fully qualified path patterns like `crate::**::services` and `regex:` selectors are supported as well.
 /// If `when_same_parent` is `true`, the access rule is only applied within the same scope of modules that share the same parent.
 #[derive(Debug, Clone)]
 pub struct MayNotBeAccessedBy {
-    accessors: HashSet<String>,
-    accessed: String,
+    accessors: HashSet<LayerSelector>,
+    accessed: LayerSelector,
     when_same_parent: bool,
 }
 
 impl MayNotBeAccessedBy {
     pub fn new(accessed: String, accessor_layers: HashSet<String>, when_same_parent: bool) -> Self {
         MayNotBeAccessedBy {
-            accessors: accessor_layers,
-            accessed,
+            accessors: accessor_layers
+                .iter()
+                .map(|layer| LayerSelector::from_layer_name(layer))
+                .collect(),
+            accessed: LayerSelector::from_layer_name(&accessed),
             when_same_parent,
         }
     }
 
-    pub fn accessors(&self) -> &HashSet<String> {
+    pub fn accessors(&self) -> &HashSet<LayerSelector> {
         &self.accessors
     }
 
-    pub fn accessed(&self) -> &String {
+    pub fn accessed(&self) -> &LayerSelector {
         &self.accessed
     }
 
     pub fn when_same_parent(&self) -> bool {
         self.when_same_parent
     }
+
+    /// Lets the layer selectors of this rule match the crates of the external layers they name, see `LayerSelector::resolve_external_layers`.
+    pub fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        self.accessors = self
+            .accessors
+            .drain()
+            .map(|mut selector| {
+                selector.resolve_external_layers(external_layers);
+                selector
+            })
+            .collect();
+        self.accessed.resolve_external_layers(external_layers);
+    }
+}
+
+impl Display for MayNotBeAccessedBy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "modules in `{}` may not be accessed by {}",
+            self.accessed,
+            quoted_list(self.accessors.iter().map(LayerSelector::as_str).sorted())
+        )?;
+        if self.when_same_parent {
+            write!(f, " (within the same parent)")?;
+        }
+        Ok(())
+    }
+}
+
+impl ViolatedRule for MayNotBeAccessedBy {
+    fn name(&self) -> &'static str {
+        "MayNotBeAccessedBy"
+    }
+
+    fn descriptor(&self) -> RuleDescriptor {
+        RuleDescriptor::new(self.name())
+            .with_parameter(
+                "accessors",
+                ParameterValue::sorted_list(self.accessors.iter()),
+            )
+            .with_parameter("accessed", ParameterValue::text(&self.accessed))
+            .with_parameter(
+                "when_same_parent",
+                ParameterValue::Bool(self.when_same_parent),
+            )
+    }
 }
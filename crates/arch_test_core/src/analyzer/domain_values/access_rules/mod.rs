@@ -1,15 +1,35 @@
+pub use self::fluent_rule::{ConditionGroup, FluentRule};
+pub use self::forbidden_paths::ForbiddenPaths;
+pub use self::item_may_not_access::ItemMayNotAccess;
+pub use self::item_may_only_be_accessed_by::ItemMayOnlyBeAccessedBy;
+pub use self::layered_architecture::LayeredArchitecture;
 pub use self::may_not_access::MayNotAccess;
 pub use self::may_not_be_accessed_by::MayNotBeAccessedBy;
 pub use self::may_only_access::MayOnlyAccess;
 pub use self::may_only_be_accessed_by::MayOnlyBeAccessedBy;
+pub use self::naming_convention::NamingConvention;
 pub use self::no_layer_cyclic_dependencies::NoLayerCyclicDependencies;
 pub use self::no_module_cyclic_dependencies::NoModuleCyclicDependencies;
+pub use self::no_named_layer_cyclic_dependencies::NoNamedLayerCyclicDependencies;
 pub use self::no_parent_access::NoParentAccess;
+pub use self::onion_architecture::OnionArchitecture;
+pub use self::unsafe_only_in::UnsafeOnlyIn;
+pub use self::visibility_rule::VisibilityRule;
 
+mod fluent_rule;
+mod forbidden_paths;
+mod item_may_not_access;
+mod item_may_only_be_accessed_by;
+mod layered_architecture;
 mod may_not_access;
 mod may_not_be_accessed_by;
 mod may_only_access;
 mod may_only_be_accessed_by;
+mod naming_convention;
 mod no_layer_cyclic_dependencies;
 mod no_module_cyclic_dependencies;
+mod no_named_layer_cyclic_dependencies;
 mod no_parent_access;
+mod onion_architecture;
+mod unsafe_only_in;
+mod visibility_rule;
@@ -0,0 +1,91 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+use crate::analyzer::domain_values::{LayerSelector, ParameterValue, RuleDescriptor, ViolatedRule};
+use crate::parser::domain_values::ObjectType;
+
+/// # Naming convention of a layer
+/// This access rule states that the names of all objects of the `object_type` that are declared in the `layer` must match the `pattern`.
+/// If `must_match` is `false`, the names must not match the `pattern` instead.
+/// In the pattern `*` matches any part of the name, e.g. `*Service`.
+///
+/// Example:
+/// ```ignore
+/// // Structs in services end with `Service`
+/// NamingConvention::new("services".to_owned(), ObjectType::Struct, "*Service".to_owned(), true);
+/// // Nothing in domain_values is a function
+/// NamingConvention::new("domain_values".to_owned(), ObjectType::Function, "*".to_owned(), false);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NamingConvention {
+    layer: LayerSelector,
+    object_type: ObjectType,
+    pattern: String,
+    must_match: bool,
+}
+
+impl NamingConvention {
+    pub fn new(layer: String, object_type: ObjectType, pattern: String, must_match: bool) -> Self {
+        NamingConvention {
+            layer: LayerSelector::from_layer_name(&layer),
+            object_type,
+            pattern,
+            must_match,
+        }
+    }
+
+    pub fn layer(&self) -> &LayerSelector {
+        &self.layer
+    }
+
+    pub fn object_type(&self) -> ObjectType {
+        self.object_type
+    }
+
+    pub fn pattern(&self) -> &String {
+        &self.pattern
+    }
+
+    pub fn must_match(&self) -> bool {
+        self.must_match
+    }
+
+    /// Lets the layer selectors of this rule match the crates of the external layers they name, see `LayerSelector::resolve_external_layers`.
+    pub fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        self.layer.resolve_external_layers(external_layers);
+    }
+}
+
+impl Display for NamingConvention {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the names of {:?} objects in `{}` {} `{}`",
+            self.object_type,
+            self.layer,
+            if self.must_match {
+                "must match"
+            } else {
+                "may not match"
+            },
+            self.pattern
+        )
+    }
+}
+
+impl ViolatedRule for NamingConvention {
+    fn name(&self) -> &'static str {
+        "NamingConvention"
+    }
+
+    fn descriptor(&self) -> RuleDescriptor {
+        RuleDescriptor::new(self.name())
+            .with_parameter("layer", ParameterValue::text(&self.layer))
+            .with_parameter(
+                "object_type",
+                ParameterValue::text(format!("{:?}", self.object_type)),
+            )
+            .with_parameter("pattern", ParameterValue::text(&self.pattern))
+            .with_parameter("must_match", ParameterValue::Bool(self.must_match))
+    }
+}
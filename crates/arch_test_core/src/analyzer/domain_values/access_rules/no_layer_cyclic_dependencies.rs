@@ -1,4 +1,23 @@
+use std::fmt::{Display, Formatter};
+
+use crate::analyzer::domain_values::ViolatedRule;
+
 /// # Forbids cyclic dependencies within the same layer
 /// As the same layer everything within the same level and below is considered (with the exception of the root level).
 #[derive(Debug, Clone)]
 pub struct NoLayerCyclicDependencies;
+
+impl Display for NoLayerCyclicDependencies {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "modules within the same layer may not depend on each other cyclically"
+        )
+    }
+}
+
+impl ViolatedRule for NoLayerCyclicDependencies {
+    fn name(&self) -> &'static str {
+        "NoLayerCyclicDependencies"
+    }
+}
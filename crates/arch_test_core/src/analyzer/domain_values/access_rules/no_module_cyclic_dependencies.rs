@@ -1,6 +1,22 @@
+use std::fmt::{Display, Formatter};
+
+use crate::analyzer::domain_values::ViolatedRule;
+
 /// # Forbids module level cyclic dependencies
 /// This access rule forbids cyclic dependencies between individual modules.
 ///
 /// Example: A uses something from B. B uses something from C and C uses something from A.
 #[derive(Debug, Clone)]
 pub struct NoModuleCyclicDependencies;
+
+impl Display for NoModuleCyclicDependencies {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "modules may not depend on each other cyclically")
+    }
+}
+
+impl ViolatedRule for NoModuleCyclicDependencies {
+    fn name(&self) -> &'static str {
+        "NoModuleCyclicDependencies"
+    }
+}
@@ -0,0 +1,68 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+use crate::analyzer::domain_values::{
+    quoted_list, LayerSelector, ParameterValue, RuleDescriptor, ViolatedRule,
+};
+
+/// # No cyclic dependencies between named layers
+/// This access rule collapses every module into the specified `layers` it belongs to and forbids cycles between these layers,
+/// e.g. `services -> materials -> services`.
+/// A module belongs to a layer, if the layer matches the module or any of its parents, i.e. it may belong to several layers.
+/// Accesses between modules that share a layer, e.g. between two modules of a nested layer, do not connect the shared layer.
+/// In contrast to `NoLayerCyclicDependencies` it does not consider the levels of the module tree.
+///
+/// Example:
+/// ```ignore
+/// NoNamedLayerCyclicDependencies::new(hash_set!["services".to_owned(), "materials".to_owned()]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NoNamedLayerCyclicDependencies {
+    layers: Vec<LayerSelector>,
+}
+
+impl NoNamedLayerCyclicDependencies {
+    pub fn new(layers: HashSet<String>) -> Self {
+        let mut layers: Vec<String> = layers.into_iter().collect();
+        layers.sort();
+        NoNamedLayerCyclicDependencies {
+            layers: layers
+                .iter()
+                .map(|layer| LayerSelector::from_layer_name(layer))
+                .collect(),
+        }
+    }
+
+    /// The layers in alphabetical order
+    pub fn layers(&self) -> &Vec<LayerSelector> {
+        &self.layers
+    }
+
+    /// Lets the layer selectors of this rule match the crates of the external layers they name, see `LayerSelector::resolve_external_layers`.
+    pub fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        for selector in self.layers.iter_mut() {
+            selector.resolve_external_layers(external_layers);
+        }
+    }
+}
+
+impl Display for NoNamedLayerCyclicDependencies {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the layers {} may not depend on each other cyclically",
+            quoted_list(self.layers.iter())
+        )
+    }
+}
+
+impl ViolatedRule for NoNamedLayerCyclicDependencies {
+    fn name(&self) -> &'static str {
+        "NoNamedLayerCyclicDependencies"
+    }
+
+    fn descriptor(&self) -> RuleDescriptor {
+        RuleDescriptor::new(self.name())
+            .with_parameter("layers", ParameterValue::list(self.layers.iter()))
+    }
+}
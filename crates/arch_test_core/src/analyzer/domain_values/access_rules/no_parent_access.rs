@@ -1,3 +1,19 @@
+use std::fmt::{Display, Formatter};
+
+use crate::analyzer::domain_values::ViolatedRule;
+
 /// # Forbids that child modules may access the parent
 #[derive(Debug, Clone)]
 pub struct NoParentAccess;
+
+impl Display for NoParentAccess {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "modules may not access their parent modules")
+    }
+}
+
+impl ViolatedRule for NoParentAccess {
+    fn name(&self) -> &'static str {
+        "NoParentAccess"
+    }
+}
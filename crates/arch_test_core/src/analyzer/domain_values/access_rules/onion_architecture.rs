@@ -0,0 +1,125 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+use crate::analyzer::domain_values::{
+    quoted_list, LayerSelector, ParameterValue, RuleDescriptor, ViolatedRule,
+};
+
+/// # Onion architecture
+/// This access rule describes an onion (or hexagonal, ports and adapters) architecture.
+/// From the inside out it consists of the `domain_model`, the `domain_services`, the `application_services` and the `adapters`.
+/// Each ring may only access itself and the rings inside of it. In addition, adapters may not access each other.
+/// Modules that are not part of any of the rings are not considered.
+#[derive(Debug, Clone)]
+pub struct OnionArchitecture {
+    domain_model: Vec<LayerSelector>,
+    domain_services: Vec<LayerSelector>,
+    application_services: Vec<LayerSelector>,
+    adapters: Vec<LayerSelector>,
+}
+
+impl OnionArchitecture {
+    pub fn new(
+        domain_model: Vec<String>,
+        domain_services: Vec<String>,
+        application_services: Vec<String>,
+        adapters: Vec<String>,
+    ) -> Self {
+        OnionArchitecture {
+            domain_model: domain_model
+                .iter()
+                .map(|layer| LayerSelector::from_layer_name(layer))
+                .collect(),
+            domain_services: domain_services
+                .iter()
+                .map(|layer| LayerSelector::from_layer_name(layer))
+                .collect(),
+            application_services: application_services
+                .iter()
+                .map(|layer| LayerSelector::from_layer_name(layer))
+                .collect(),
+            adapters: adapters
+                .iter()
+                .map(|layer| LayerSelector::from_layer_name(layer))
+                .collect(),
+        }
+    }
+
+    pub fn domain_model(&self) -> &Vec<LayerSelector> {
+        &self.domain_model
+    }
+
+    pub fn domain_services(&self) -> &Vec<LayerSelector> {
+        &self.domain_services
+    }
+
+    pub fn application_services(&self) -> &Vec<LayerSelector> {
+        &self.application_services
+    }
+
+    pub fn adapters(&self) -> &Vec<LayerSelector> {
+        &self.adapters
+    }
+
+    /// Returns the rings from the inside out, together with the name of the ring
+    pub fn rings(&self) -> [(&'static str, &Vec<LayerSelector>); 4] {
+        [
+            ("Domain model", &self.domain_model),
+            ("Domain service", &self.domain_services),
+            ("Application service", &self.application_services),
+            ("Adapter", &self.adapters),
+        ]
+    }
+
+    /// Lets the layer selectors of this rule match the crates of the external layers they name, see `LayerSelector::resolve_external_layers`.
+    pub fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        for selector in self.domain_model.iter_mut() {
+            selector.resolve_external_layers(external_layers);
+        }
+        for selector in self.domain_services.iter_mut() {
+            selector.resolve_external_layers(external_layers);
+        }
+        for selector in self.application_services.iter_mut() {
+            selector.resolve_external_layers(external_layers);
+        }
+        for selector in self.adapters.iter_mut() {
+            selector.resolve_external_layers(external_layers);
+        }
+    }
+}
+
+impl Display for OnionArchitecture {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the onion rings domain model {}, domain services {}, application services {} and adapters {} may only access themselves and the rings inside of them, adapters may not access each other",
+            quoted_list(self.domain_model.iter()),
+            quoted_list(self.domain_services.iter()),
+            quoted_list(self.application_services.iter()),
+            quoted_list(self.adapters.iter())
+        )
+    }
+}
+
+impl ViolatedRule for OnionArchitecture {
+    fn name(&self) -> &'static str {
+        "OnionArchitecture"
+    }
+
+    fn descriptor(&self) -> RuleDescriptor {
+        RuleDescriptor::new(self.name())
+            .with_parameter(
+                "domain_model",
+                ParameterValue::list(self.domain_model.iter()),
+            )
+            .with_parameter(
+                "domain_services",
+                ParameterValue::list(self.domain_services.iter()),
+            )
+            .with_parameter(
+                "application_services",
+                ParameterValue::list(self.application_services.iter()),
+            )
+            .with_parameter("adapters", ParameterValue::list(self.adapters.iter()))
+    }
+}
@@ -0,0 +1,68 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+use itertools::Itertools;
+
+use crate::analyzer::domain_values::{
+    quoted_list, LayerSelector, ParameterValue, RuleDescriptor, ViolatedRule,
+};
+
+/// # Unsafe code only in `layers`
+/// This access rule states that `unsafe` blocks, `unsafe fn`, `unsafe impl`, `unsafe trait` and `extern` blocks may only appear in the specified `layers`.
+///
+/// Example:
+/// ```ignore
+/// UnsafeOnlyIn::new(hash_set!["ffi".to_owned(), "sys".to_owned()]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct UnsafeOnlyIn {
+    layers: HashSet<LayerSelector>,
+}
+
+impl UnsafeOnlyIn {
+    pub fn new(layers: HashSet<String>) -> Self {
+        UnsafeOnlyIn {
+            layers: layers
+                .iter()
+                .map(|layer| LayerSelector::from_layer_name(layer))
+                .collect(),
+        }
+    }
+
+    pub fn layers(&self) -> &HashSet<LayerSelector> {
+        &self.layers
+    }
+
+    /// Lets the layer selectors of this rule match the crates of the external layers they name, see `LayerSelector::resolve_external_layers`.
+    pub fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        self.layers = self
+            .layers
+            .drain()
+            .map(|mut selector| {
+                selector.resolve_external_layers(external_layers);
+                selector
+            })
+            .collect();
+    }
+}
+
+impl Display for UnsafeOnlyIn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unsafe code may only appear in {}",
+            quoted_list(self.layers.iter().map(LayerSelector::as_str).sorted())
+        )
+    }
+}
+
+impl ViolatedRule for UnsafeOnlyIn {
+    fn name(&self) -> &'static str {
+        "UnsafeOnlyIn"
+    }
+
+    fn descriptor(&self) -> RuleDescriptor {
+        RuleDescriptor::new(self.name())
+            .with_parameter("layers", ParameterValue::sorted_list(self.layers.iter()))
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+use crate::analyzer::domain_values::{LayerSelector, ParameterValue, RuleDescriptor, ViolatedRule};
+use crate::parser::domain_values::{ObjectType, Visibility};
+
+/// # Visibility of the objects of a layer
+/// This access rule states that all objects of the `object_type` that are declared in the `layer` have a visibility of at most or at least `visibility`.
+/// Visibilities are ordered from private to `pub`, see `Visibility`.
+///
+/// Example:
+/// ```ignore
+/// // Nothing in internal may be `pub` beyond `pub(crate)`
+/// VisibilityRule::at_most("internal".to_owned(), ObjectType::Struct, Visibility::Crate);
+/// // All structs in domain_values must be public
+/// VisibilityRule::at_least("domain_values".to_owned(), ObjectType::Struct, Visibility::Public);
+/// ```
+#[derive(Debug, Clone)]
+pub struct VisibilityRule {
+    layer: LayerSelector,
+    object_type: ObjectType,
+    visibility: Visibility,
+    at_most: bool,
+}
+
+impl VisibilityRule {
+    /// The objects may not be more visible than `visibility`
+    pub fn at_most(layer: String, object_type: ObjectType, visibility: Visibility) -> Self {
+        VisibilityRule {
+            layer: LayerSelector::from_layer_name(&layer),
+            object_type,
+            visibility,
+            at_most: true,
+        }
+    }
+
+    /// The objects must be at least as visible as `visibility`
+    pub fn at_least(layer: String, object_type: ObjectType, visibility: Visibility) -> Self {
+        VisibilityRule {
+            layer: LayerSelector::from_layer_name(&layer),
+            object_type,
+            visibility,
+            at_most: false,
+        }
+    }
+
+    pub fn layer(&self) -> &LayerSelector {
+        &self.layer
+    }
+
+    pub fn object_type(&self) -> ObjectType {
+        self.object_type
+    }
+
+    pub fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+
+    pub fn is_at_most(&self) -> bool {
+        self.at_most
+    }
+
+    /// Returns `true` if an object with the specified `visibility` satisfies this rule
+    pub fn allows(&self, visibility: &Visibility) -> bool {
+        if self.at_most {
+            visibility.rank() <= self.visibility.rank()
+        } else {
+            visibility.rank() >= self.visibility.rank()
+        }
+    }
+
+    /// Lets the layer selectors of this rule match the crates of the external layers they name, see `LayerSelector::resolve_external_layers`.
+    pub fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        self.layer.resolve_external_layers(external_layers);
+    }
+}
+
+impl Display for VisibilityRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?} objects in `{}` must be at {} `{}`",
+            self.object_type,
+            self.layer,
+            if self.at_most { "most" } else { "least" },
+            self.visibility
+        )
+    }
+}
+
+impl ViolatedRule for VisibilityRule {
+    fn name(&self) -> &'static str {
+        "VisibilityRule"
+    }
+
+    fn descriptor(&self) -> RuleDescriptor {
+        RuleDescriptor::new(self.name())
+            .with_parameter("layer", ParameterValue::text(&self.layer))
+            .with_parameter(
+                "object_type",
+                ParameterValue::text(format!("{:?}", self.object_type)),
+            )
+            .with_parameter("visibility", ParameterValue::text(&self.visibility))
+            .with_parameter("at_most", ParameterValue::Bool(self.at_most))
+    }
+}
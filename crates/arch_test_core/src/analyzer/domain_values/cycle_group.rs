@@ -0,0 +1,35 @@
+use crate::parser::domain_values::UseRelation;
+
+/// # A group of modules that depend on each other
+/// It is a strongly connected component of the dependency graph with more than one member.
+/// The `cycle` is a shortest cycle of `UseRelation`s through the first member.
+/// Its `path` are the graph nodes it passes, starting and ending with the first member.
+#[derive(Debug, Clone)]
+pub struct CycleGroup {
+    members: Vec<usize>,
+    path: Vec<usize>,
+    cycle: Vec<UseRelation>,
+}
+
+impl CycleGroup {
+    pub fn new(members: Vec<usize>, path: Vec<usize>, cycle: Vec<UseRelation>) -> Self {
+        CycleGroup {
+            members,
+            path,
+            cycle,
+        }
+    }
+
+    /// The indices of the involved graph nodes, e.g. the modules, in ascending order
+    pub fn members(&self) -> &Vec<usize> {
+        &self.members
+    }
+
+    pub fn path(&self) -> &Vec<usize> {
+        &self.path
+    }
+
+    pub fn cycle(&self) -> &Vec<UseRelation> {
+        &self.cycle
+    }
+}
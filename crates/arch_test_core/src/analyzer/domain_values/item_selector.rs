@@ -0,0 +1,84 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+use crate::analyzer::domain_values::{matches_segment, LayerSelector};
+use crate::parser::domain_values::ObjectType;
+
+/// # Selects individual items of a project
+/// An item is a struct, enum, trait, function or type alias.
+/// Items are selected by a name pattern, in which `*` matches any part of the name, e.g. `*Repository`.
+/// The selection can be narrowed down to certain kinds of items and to the items of a layer.
+/// Objects of external crates are selected by their last path segment, e.g. `PgPool` for `sqlx::PgPool`.
+///
+/// Example:
+/// ```ignore
+/// let repositories = ItemSelector::new("*Repository").with_kind(ObjectType::Struct).with_layer("adapters");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemSelector {
+    name_pattern: String,
+    kinds: Vec<ObjectType>,
+    layer: Option<LayerSelector>,
+}
+
+impl ItemSelector {
+    pub fn new(name_pattern: &str) -> Self {
+        ItemSelector {
+            name_pattern: name_pattern.to_owned(),
+            kinds: vec![],
+            layer: None,
+        }
+    }
+
+    /// Only selects items of the specified kind. Can be called multiple times to select several kinds.
+    pub fn with_kind(mut self, kind: ObjectType) -> Self {
+        self.kinds.push(kind);
+        self
+    }
+
+    /// Only selects items that are declared within the layer, see `LayerSelector`.
+    pub fn with_layer(mut self, layer: &str) -> Self {
+        self.layer = Some(LayerSelector::from_layer_name(layer));
+        self
+    }
+
+    /// Lets the layer selector match the crates of the external layer it names, see `LayerSelector::resolve_external_layers`.
+    pub fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        if let Some(layer) = self.layer.as_mut() {
+            layer.resolve_external_layers(external_layers);
+        }
+    }
+
+    pub fn name_pattern(&self) -> &String {
+        &self.name_pattern
+    }
+
+    pub fn kinds(&self) -> &Vec<ObjectType> {
+        &self.kinds
+    }
+
+    pub fn layer(&self) -> Option<&LayerSelector> {
+        self.layer.as_ref()
+    }
+
+    /// Returns `true` if the name and the kind of the item are selected, ignoring the layer.
+    /// Items of unknown kind, e.g. `impl` blocks of foreign types, are only selected if no kind is specified.
+    pub fn matches_item(&self, name: &str, kind: Option<ObjectType>) -> bool {
+        let name = name.rsplit("::").next().unwrap_or(name);
+        matches_segment(&self.name_pattern, name)
+            && (self.kinds.is_empty() || kind.is_some_and(|kind| self.kinds.contains(&kind)))
+    }
+}
+
+impl Display for ItemSelector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name_pattern)?;
+        if !self.kinds.is_empty() {
+            write!(f, " ({:?})", self.kinds)?;
+        }
+        if let Some(layer) = &self.layer {
+            write!(f, " in {}", layer)?;
+        }
+        Ok(())
+    }
+}
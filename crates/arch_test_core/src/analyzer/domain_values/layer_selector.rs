@@ -0,0 +1,175 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+use regex::Regex;
+
+/// # Selects the modules that belong to a layer
+/// A layer selector is parsed from a string and is one of the following:
+/// * A module name, e.g. `services`. It matches every module with this name and all modules within it.
+/// * A path pattern, e.g. `crate::billing::*::services` or `crate::**::adapters::db`.
+///   `*` matches exactly one path segment (or a part of it, e.g. `*_service`), `**` matches any number of segments.
+///   It matches every module whose path matches the pattern and all modules within it.
+///   A pattern that does not start with `crate` or `**` is relative, e.g. `*_service` or `billing::*`,
+///   and is matched at any depth as if it started with `**`.
+/// * A regular expression prefixed with `regex:`, e.g. `regex:^crate::(billing|shipping)::services$`.
+///   It is matched against the fully qualified path of a module and its parents.
+///
+/// A module name may also name an external layer, i.e. a group of external crates, see `resolve_external_layers`.
+#[derive(Clone)]
+pub struct LayerSelector {
+    selector: String,
+    kind: SelectorKind,
+    external_crates: HashSet<String>,
+}
+
+#[derive(Debug, Clone)]
+enum SelectorKind {
+    Name,
+    Path(Vec<String>),
+    Regex(Regex),
+    /// A `regex:` selector that is not a valid regular expression, it does not match any module
+    Invalid,
+}
+
+impl LayerSelector {
+    /// Parses the selector, see `parse`.
+    /// Panics if a `regex:` selector is not a valid regular expression, which is why it is only meant for selectors written in code.
+    pub fn new(selector: &str) -> Self {
+        LayerSelector::parse(selector)
+            .unwrap_or_else(|_| panic!("Invalid regex selector: {}", selector))
+    }
+
+    /// Parses a layer name of the architecture or of an access rule, see `parse`.
+    /// An invalid selector does not match any module, it is reported by `Architecture::validate_access_rules` instead.
+    pub(crate) fn from_layer_name(layer_name: &str) -> Self {
+        LayerSelector::parse(layer_name).unwrap_or_else(|_| LayerSelector {
+            selector: layer_name.to_owned(),
+            kind: SelectorKind::Invalid,
+            external_crates: HashSet::new(),
+        })
+    }
+
+    /// Parses the selector. Fails if a `regex:` selector is not a valid regular expression.
+    pub fn parse(selector: &str) -> Result<Self, regex::Error> {
+        let kind = if let Some(regex) = selector.strip_prefix("regex:") {
+            SelectorKind::Regex(Regex::new(regex)?)
+        } else if selector.contains("::") || selector.contains('*') {
+            let mut pattern: Vec<String> = selector
+                .split("::")
+                .map(|segment| segment.to_owned())
+                .collect();
+            if pattern[0] != "crate" && pattern[0] != "**" {
+                pattern.insert(0, "**".to_owned());
+            }
+            SelectorKind::Path(pattern)
+        } else {
+            SelectorKind::Name
+        };
+        Ok(LayerSelector {
+            selector: selector.to_owned(),
+            kind,
+            external_crates: HashSet::new(),
+        })
+    }
+
+    /// Lets the selector match the crates of the external layer it names, if any of the `external_layers` has its name.
+    /// The external layers map layer names to crate names, see `Architecture::with_external_layer`.
+    pub fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        if let SelectorKind::Name = self.kind {
+            self.external_crates = external_layers
+                .get(&self.selector)
+                .cloned()
+                .unwrap_or_default();
+        }
+    }
+
+    /// Returns `true` if the external crate `crate_name` belongs to the external layer this selector names.
+    pub fn matches_external_crate(&self, crate_name: &str) -> bool {
+        self.external_crates.contains(crate_name)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.selector
+    }
+
+    /// Returns `true` if the module with the specified fully qualified path is selected, ignoring its parents.
+    pub fn matches_path(&self, fully_qualified_path: &str) -> bool {
+        match &self.kind {
+            SelectorKind::Name => fully_qualified_path.rsplit("::").next() == Some(&self.selector),
+            SelectorKind::Path(pattern) => {
+                let segments: Vec<&str> = fully_qualified_path.split("::").collect();
+                matches_path_pattern(pattern, &segments)
+            }
+            SelectorKind::Regex(regex) => regex.is_match(fully_qualified_path),
+            SelectorKind::Invalid => false,
+        }
+    }
+}
+
+/// Matches the `segments` of a path against a pattern of segments, that may contain `*` and `**` wildcards.
+pub fn matches_path_pattern<P: AsRef<str>, S: AsRef<str>>(pattern: &[P], segments: &[S]) -> bool {
+    match pattern.first().map(|segment| segment.as_ref()) {
+        None => segments.is_empty(),
+        Some("**") => (0..=segments.len())
+            .any(|skipped| matches_path_pattern(&pattern[1..], &segments[skipped..])),
+        Some(pattern_segment) => {
+            !segments.is_empty()
+                && matches_segment(pattern_segment, segments[0].as_ref())
+                && matches_path_pattern(&pattern[1..], &segments[1..])
+        }
+    }
+}
+
+/// Matches a single path segment against a pattern, in which `*` matches any part of the segment.
+pub fn matches_segment(pattern: &str, segment: &str) -> bool {
+    match pattern.find('*') {
+        None => pattern == segment,
+        Some(position) => {
+            let prefix = &pattern[..position];
+            let rest = &pattern[(position + 1)..];
+            segment.starts_with(prefix)
+                && segment
+                    .char_indices()
+                    .map(|(start, _)| start)
+                    .chain(std::iter::once(segment.len()))
+                    .filter(|start| *start >= prefix.len())
+                    .any(|start| matches_segment(rest, &segment[start..]))
+        }
+    }
+}
+
+/// Quotes the `items` with backticks and separates them by commas, e.g. "`services`, `materials`".
+pub fn quoted_list<T: Display>(items: impl IntoIterator<Item = T>) -> String {
+    items
+        .into_iter()
+        .map(|item| format!("`{}`", item))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+impl Display for LayerSelector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.selector)
+    }
+}
+
+impl Debug for LayerSelector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.selector)
+    }
+}
+
+impl Hash for LayerSelector {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.selector.hash(state);
+    }
+}
+
+impl PartialEq for LayerSelector {
+    fn eq(&self, other: &Self) -> bool {
+        self.selector == other.selector
+    }
+}
+
+impl Eq for LayerSelector {}
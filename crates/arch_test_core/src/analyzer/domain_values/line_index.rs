@@ -0,0 +1,68 @@
+use ra_ap_syntax::TextRange;
+
+use crate::analyzer::domain_values::SourceSpan;
+
+/// # Index of the lines of a source file
+/// It is computed once per file and locates offsets, e.g. of a `TextRange`, in lines and columns.
+/// Newlines are part of the offsets, lines end with `\n` or `\r\n`.
+#[derive(Debug, Clone)]
+pub struct LineIndex {
+    text: String,
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    pub fn new(text: String) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        LineIndex { text, line_starts }
+    }
+
+    /// Returns the line and the column of the `offset`, both starting at 1.
+    /// Columns count characters. Returns `None` if the offset is not within the text.
+    pub fn line_column(&self, offset: usize) -> Option<(usize, usize)> {
+        if offset > self.text.len() || !self.text.is_char_boundary(offset) {
+            return None;
+        }
+        let line_index = match self.line_starts.binary_search(&offset) {
+            Ok(line_index) => line_index,
+            Err(next_line_index) => next_line_index - 1,
+        };
+        let column = self.text[self.line_starts[line_index]..offset]
+            .chars()
+            .count();
+        Some((line_index + 1, column + 1))
+    }
+
+    /// Locates the `text_range`. Returns `None` if it is not within the text.
+    pub fn span(&self, text_range: &TextRange) -> Option<SourceSpan> {
+        let (start_line, start_column) = self.line_column(u32::from(text_range.start()) as usize)?;
+        let (end_line, end_column) = self.line_column(u32::from(text_range.end()) as usize)?;
+        Some(SourceSpan::new(
+            start_line,
+            start_column,
+            end_line,
+            end_column,
+        ))
+    }
+
+    /// Returns the content of the `line`, starting at 1, without its line ending
+    pub fn line(&self, line: usize) -> Option<&str> {
+        let start = *self.line_starts.get(line.checked_sub(1)?)?;
+        let end = self
+            .line_starts
+            .get(line)
+            .copied()
+            .unwrap_or(self.text.len());
+        Some(
+            self.text[start..end]
+                .trim_end_matches('\n')
+                .trim_end_matches('\r'),
+        )
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+}
@@ -1,5 +1,29 @@
+pub use self::cycle_group::CycleGroup;
+pub use self::item_selector::ItemSelector;
+pub use self::layer_selector::{matches_path_pattern, matches_segment, quoted_list, LayerSelector};
+pub use self::line_index::LineIndex;
+pub use self::module_selection::{modules, ModuleSelection};
+pub use self::parameter_value::ParameterValue;
+pub use self::rule_descriptor::RuleDescriptor;
+pub use self::rule_record::RuleRecord;
 pub use self::rule_violation_type::RuleViolationType;
+pub use self::severity::Severity;
+pub use self::source_span::SourceSpan;
+pub use self::violated_rule::ViolatedRule;
+pub use self::violation_location::ViolationLocation;
 
 /// Access rules that define the Architecture
 pub mod access_rules;
+mod cycle_group;
+mod item_selector;
+mod layer_selector;
+mod line_index;
+mod module_selection;
+mod parameter_value;
+mod rule_descriptor;
+mod rule_record;
 mod rule_violation_type;
+mod severity;
+mod source_span;
+mod violated_rule;
+mod violation_location;
@@ -0,0 +1,43 @@
+use crate::analyzer::domain_values::access_rules::FluentRule;
+
+/// Starts a rule of the fluent rule DSL, see `FluentRule`.
+///
+/// Example:
+/// ```ignore
+/// modules().that().reside_in("services").should().only_be_accessed_by(["tests", "materials"]);
+/// ```
+pub fn modules() -> ModuleSelection {
+    ModuleSelection { layers: vec![] }
+}
+
+/// # Selects the modules a `FluentRule` applies to
+/// Modules are selected by the layers they reside in. Several layers are combined with `or()`.
+#[derive(Debug, Clone)]
+pub struct ModuleSelection {
+    layers: Vec<String>,
+}
+
+impl ModuleSelection {
+    pub fn that(self) -> Self {
+        self
+    }
+
+    /// Selects the modules of the `layer`, see `LayerSelector`
+    pub fn reside_in(mut self, layer: &str) -> Self {
+        self.layers.push(layer.to_owned());
+        self
+    }
+
+    pub fn or(self) -> Self {
+        self
+    }
+
+    /// Finishes the selection. The conditions of the selected modules follow.
+    pub fn should(self) -> FluentRule {
+        FluentRule::new(self.layers)
+    }
+
+    pub fn layers(&self) -> &Vec<String> {
+        &self.layers
+    }
+}
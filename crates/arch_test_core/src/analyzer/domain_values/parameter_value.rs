@@ -0,0 +1,45 @@
+use std::fmt::{Display, Formatter};
+
+use itertools::Itertools;
+
+/// # Value of a parameter of a `RuleDescriptor`
+/// With the feature `serde` it is serialized as a plain JSON value, i.e. a boolean, a string or an array of strings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum ParameterValue {
+    Bool(bool),
+    Text(String),
+    List(Vec<String>),
+}
+
+impl ParameterValue {
+    pub fn text<T: Display>(value: T) -> Self {
+        ParameterValue::Text(value.to_string())
+    }
+
+    /// A list of the `items` in their order, e.g. the layers of a `LayeredArchitecture` from top to bottom
+    pub fn list<T: Display>(items: impl IntoIterator<Item = T>) -> Self {
+        ParameterValue::List(items.into_iter().map(|item| item.to_string()).collect())
+    }
+
+    /// A sorted list of the `items`, e.g. of a set of layers
+    pub fn sorted_list<T: Display>(items: impl IntoIterator<Item = T>) -> Self {
+        ParameterValue::List(
+            items
+                .into_iter()
+                .map(|item| item.to_string())
+                .sorted()
+                .collect(),
+        )
+    }
+}
+
+impl Display for ParameterValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParameterValue::Bool(value) => write!(f, "{}", value),
+            ParameterValue::Text(value) => write!(f, "{}", value),
+            ParameterValue::List(items) => write!(f, "[{}]", items.join(", ")),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use crate::analyzer::domain_values::ParameterValue;
+
+/// # Kind and parameters of a violated rule, see `ViolatedRule::descriptor`
+/// E.g. `MayNotAccess::new("parser".to_owned(), hash_set!["analyzer".to_owned()], true)`
+/// is described by the kind `MayNotAccess` and the parameters `accessor`, `accessed` and `when_same_parent`.
+/// Parameters are ordered by their name and sets of values are sorted, so that the description of a rule is stable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RuleDescriptor {
+    kind: String,
+    parameters: BTreeMap<String, ParameterValue>,
+}
+
+impl RuleDescriptor {
+    pub fn new(kind: &str) -> Self {
+        RuleDescriptor {
+            kind: kind.to_owned(),
+            parameters: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_parameter(mut self, name: &str, value: ParameterValue) -> Self {
+        self.parameters.insert(name.to_owned(), value);
+        self
+    }
+
+    pub fn kind(&self) -> &String {
+        &self.kind
+    }
+
+    pub fn parameters(&self) -> &BTreeMap<String, ParameterValue> {
+        &self.parameters
+    }
+}
+
+impl Display for RuleDescriptor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.parameters.is_empty() {
+            return write!(f, "{}", self.kind);
+        }
+        write!(
+            f,
+            "{} {{ {} }}",
+            self.kind,
+            self.parameters
+                .iter()
+                .map(|(name, value)| format!("{}: {}", name, value))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
@@ -0,0 +1,44 @@
+use crate::analyzer::domain_values::{RuleDescriptor, Severity};
+
+/// # Serializable description of an access rule of an `Architecture`
+/// The `index` is the position of the rule in the architecture, which violations refer to, see `RuleViolation::rule_index`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RuleRecord {
+    index: usize,
+    rule: RuleDescriptor,
+    severity: Severity,
+    description: String,
+}
+
+impl RuleRecord {
+    pub fn new(
+        index: usize,
+        rule: RuleDescriptor,
+        severity: Severity,
+        description: String,
+    ) -> Self {
+        RuleRecord {
+            index,
+            rule,
+            severity,
+            description,
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn rule(&self) -> &RuleDescriptor {
+        &self.rule
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    pub fn description(&self) -> &String {
+        &self.description
+    }
+}
@@ -1,7 +1,17 @@
-#[derive(Debug, Copy, Clone)]
+/// Describes which kind of information a `RuleViolation` carries
+/// * `SingleLocation`: A single `UseRelation` that violates the rule
+/// * `SingleObject`: A single object that violates the rule, e.g. a misnamed struct or a use of a forbidden path
+/// * `Cycle`: The `UseRelation`s that form a cycle
+/// * `UnusedSuppression`: A suppression that does not suppress any violation
+/// * `IncompleteLayerSpecification`: Not every module is covered by a layer
+/// * `LayerDoNotExist`: The rule refers to layers that are not part of the architecture
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum RuleViolationType {
     SingleLocation,
+    SingleObject,
     Cycle,
+    UnusedSuppression,
     IncompleteLayerSpecification,
     LayerDoNotExist,
 }
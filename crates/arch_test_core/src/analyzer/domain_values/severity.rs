@@ -0,0 +1,25 @@
+use std::fmt::{Display, Formatter};
+
+/// # Severity of an access rule
+/// * `Error`: Violations fail the check
+/// * `Warning`: Violations are reported, but do not fail the check
+/// * `Off`: The rule is not checked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Severity {
+    #[default]
+    Error,
+    Warning,
+    Off,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Off => write!(f, "off"),
+        }
+    }
+}
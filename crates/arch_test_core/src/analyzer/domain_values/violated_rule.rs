@@ -0,0 +1,37 @@
+use std::fmt::{Debug, Display};
+
+use crate::analyzer::domain_values::RuleDescriptor;
+
+/// # A rule that can be violated, see `RuleViolation::access_rule`
+/// It is described for humans by `Display`, e.g. "modules in `parser` may not access `analyzer`",
+/// and for tools by its `name` and `descriptor`.
+/// Every `AccessRule` is a violated rule, as are suppressions that have to suppress a violation.
+pub trait ViolatedRule: Debug + Display {
+    /// Returns the name suppressions and baselines refer to, e.g. `MayNotAccess` in `// archtest-allow(MayNotAccess)`.
+    fn name(&self) -> &'static str;
+    /// Returns the kind and parameters of the rule, e.g. for reports.
+    /// By default the kind is the `name` and there are no parameters.
+    fn descriptor(&self) -> RuleDescriptor {
+        RuleDescriptor::new(self.name())
+    }
+}
+
+impl<T: ViolatedRule + ?Sized> ViolatedRule for &T {
+    fn name(&self) -> &'static str {
+        (**self).name()
+    }
+
+    fn descriptor(&self) -> RuleDescriptor {
+        (**self).descriptor()
+    }
+}
+
+impl<T: ViolatedRule + ?Sized> ViolatedRule for Box<T> {
+    fn name(&self) -> &'static str {
+        (**self).name()
+    }
+
+    fn descriptor(&self) -> RuleDescriptor {
+        (**self).descriptor()
+    }
+}
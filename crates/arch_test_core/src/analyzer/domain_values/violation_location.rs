@@ -0,0 +1,45 @@
+use ra_ap_syntax::TextRange;
+
+/// # Location of a violation that is not caused by a use between modules
+/// It locates the object `object_name` at the `text_range` within the module at `node_index`,
+/// e.g. a misnamed struct, a use of a forbidden path, an `unsafe block` or a suppression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViolationLocation {
+    node_index: usize,
+    module_path: String,
+    object_name: String,
+    text_range: TextRange,
+}
+
+impl ViolationLocation {
+    pub fn new(
+        node_index: usize,
+        module_path: String,
+        object_name: String,
+        text_range: TextRange,
+    ) -> Self {
+        ViolationLocation {
+            node_index,
+            module_path,
+            object_name,
+            text_range,
+        }
+    }
+
+    pub fn node_index(&self) -> usize {
+        self.node_index
+    }
+
+    /// The fully qualified path of the module at `node_index`
+    pub fn module_path(&self) -> &String {
+        &self.module_path
+    }
+
+    pub fn object_name(&self) -> &String {
+        &self.object_name
+    }
+
+    pub fn text_range(&self) -> &TextRange {
+        &self.text_range
+    }
+}
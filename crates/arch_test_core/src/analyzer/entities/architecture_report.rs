@@ -0,0 +1,71 @@
+use crate::analyzer::domain_values::{RuleRecord, Severity};
+use crate::analyzer::entities::{RuleViolation, SourceFiles, ViolationRecord};
+use crate::parser::entities::ModuleNode;
+
+/// # Serializable report of the violations of an `Architecture`
+/// It is the machine-readable counterpart of `ViolationReport::print`.
+/// With the feature `serde` it can be serialized, e.g. to JSON.
+///
+/// Example:
+/// ```ignore
+/// let report = architecture.check_all_access_rules(&module_tree).err();
+/// let architecture_report = ArchitectureReport::new(report.iter().flat_map(|report| report.violations()), module_tree.tree())
+///     .with_rules(architecture.rule_records());
+/// println!("{}", serde_json::to_string_pretty(&architecture_report).unwrap());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ArchitectureReport {
+    rules: Vec<RuleRecord>,
+    error_count: usize,
+    warning_count: usize,
+    violations: Vec<ViolationRecord>,
+}
+
+impl ArchitectureReport {
+    pub fn new<'a, 'r: 'a>(
+        violations: impl IntoIterator<Item = &'a RuleViolation<'r>>,
+        tree: &[ModuleNode],
+    ) -> Self {
+        let mut source_files = SourceFiles::new();
+        let violations: Vec<ViolationRecord> = violations
+            .into_iter()
+            .map(|violation| violation.to_record(tree, &mut source_files))
+            .collect();
+        ArchitectureReport {
+            rules: Vec::new(),
+            error_count: count_severity(&violations, Severity::Error),
+            warning_count: count_severity(&violations, Severity::Warning),
+            violations,
+        }
+    }
+
+    /// Adds the rules of the architecture, see `Architecture::rule_records`
+    pub fn with_rules(mut self, rules: Vec<RuleRecord>) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    pub fn rules(&self) -> &Vec<RuleRecord> {
+        &self.rules
+    }
+
+    pub fn error_count(&self) -> usize {
+        self.error_count
+    }
+
+    pub fn warning_count(&self) -> usize {
+        self.warning_count
+    }
+
+    pub fn violations(&self) -> &Vec<ViolationRecord> {
+        &self.violations
+    }
+}
+
+fn count_severity(violations: &[ViolationRecord], severity: Severity) -> usize {
+    violations
+        .iter()
+        .filter(|violation| violation.severity() == severity)
+        .count()
+}
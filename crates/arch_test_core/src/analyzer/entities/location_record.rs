@@ -0,0 +1,48 @@
+use crate::analyzer::domain_values::{SourceSpan, ViolationLocation};
+use crate::analyzer::entities::SourceFiles;
+use crate::parser::entities::ModuleNode;
+
+/// # Serializable description of a `ViolationLocation`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LocationRecord {
+    file: String,
+    module_path: String,
+    object_name: String,
+    span: Option<SourceSpan>,
+}
+
+impl LocationRecord {
+    /// Describes the `location`, the span is located in the file of its module that is read from `source_files`
+    pub fn new(
+        location: &ViolationLocation,
+        tree: &[ModuleNode],
+        source_files: &mut SourceFiles,
+    ) -> Self {
+        let file_path = tree[location.node_index()].file_path();
+        LocationRecord {
+            file: file_path.clone(),
+            module_path: location.module_path().clone(),
+            object_name: location.object_name().clone(),
+            span: source_files
+                .line_index(file_path)
+                .and_then(|line_index| line_index.span(location.text_range())),
+        }
+    }
+
+    pub fn file(&self) -> &String {
+        &self.file
+    }
+
+    pub fn module_path(&self) -> &String {
+        &self.module_path
+    }
+
+    pub fn object_name(&self) -> &String {
+        &self.object_name
+    }
+
+    pub fn span(&self) -> Option<SourceSpan> {
+        self.span
+    }
+}
@@ -1,3 +1,17 @@
+pub use self::architecture_report::ArchitectureReport;
+pub use self::location_record::LocationRecord;
+pub use self::object_record::ObjectRecord;
 pub use self::rule_violation::RuleViolation;
+pub use self::source_files::SourceFiles;
+pub use self::use_record::UseRecord;
+pub use self::violation_record::ViolationRecord;
+pub use self::violation_report::ViolationReport;
 
+mod architecture_report;
+mod location_record;
+mod object_record;
 mod rule_violation;
+mod source_files;
+mod use_record;
+mod violation_record;
+mod violation_report;
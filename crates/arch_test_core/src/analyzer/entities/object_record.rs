@@ -0,0 +1,71 @@
+use crate::analyzer::domain_values::SourceSpan;
+use crate::analyzer::entities::SourceFiles;
+use crate::parser::domain_values::{ObjectType, ObjectUse};
+use crate::parser::entities::ModuleNode;
+
+/// # Serializable description of an object involved in a violation
+/// External objects have neither a file nor a span.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ObjectRecord {
+    file: Option<String>,
+    module_path: String,
+    object_path: String,
+    object_name: String,
+    object_kind: ObjectType,
+    span: Option<SourceSpan>,
+}
+
+impl ObjectRecord {
+    /// Describes the `object_use`, the span is located in the file of the object that is read from `source_files`
+    pub fn new(
+        object_use: &ObjectUse,
+        tree: &[ModuleNode],
+        source_files: &mut SourceFiles,
+    ) -> Self {
+        let node = &tree[object_use.node_index()];
+        let usable_object = object_use.usable_object();
+        let (file, span) = if node.is_external() {
+            (None, None)
+        } else {
+            (
+                Some(node.file_path().clone()),
+                source_files
+                    .line_index(node.file_path())
+                    .and_then(|line_index| line_index.span(usable_object.text_range())),
+            )
+        };
+        ObjectRecord {
+            file,
+            module_path: node.get_fully_qualified_path(tree),
+            object_path: object_use.full_module_path().clone(),
+            object_name: usable_object.object_name().clone(),
+            object_kind: usable_object.object_type(),
+            span,
+        }
+    }
+
+    pub fn file(&self) -> Option<&String> {
+        self.file.as_ref()
+    }
+
+    pub fn module_path(&self) -> &String {
+        &self.module_path
+    }
+
+    pub fn object_path(&self) -> &String {
+        &self.object_path
+    }
+
+    pub fn object_name(&self) -> &String {
+        &self.object_name
+    }
+
+    pub fn object_kind(&self) -> ObjectType {
+        self.object_kind
+    }
+
+    pub fn span(&self) -> Option<SourceSpan> {
+        self.span
+    }
+}
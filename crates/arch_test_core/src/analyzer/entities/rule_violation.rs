@@ -1,33 +1,100 @@
-use std::fmt::Debug;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::fmt::{Display, Formatter};
 
-use ra_ap_syntax::{TextRange, TextSize};
-
-use crate::analyzer::domain_values::RuleViolationType;
+use crate::analyzer::domain_values::{
+    RuleViolationType, Severity, ViolatedRule, ViolationLocation,
+};
+use crate::analyzer::entities::{LocationRecord, SourceFiles, UseRecord, ViolationRecord};
 use crate::parser::domain_values::UseRelation;
 use crate::parser::entities::ModuleNode;
 
+/// Describes a violation of an access rule, including the `UseRelation`s involved.
+/// Violations that are not caused by a use between modules, e.g. of an `unsafe` block, are located by `ViolationLocation`s instead.
+/// It implements `std::error::Error`, so it can be propagated with `?` as `Box<dyn Error + '_>`.
+/// It is neither `Send` nor `Sync` in general, since the violated rule is not required to be either.
+/// Its `Display` output is a single line like "error: modules in `parser` may not access `analyzer` (`crate::parser::x` -> `crate::analyzer::Y`)".
 #[derive(Debug)]
 pub struct RuleViolation<'r> {
     violation_type: RuleViolationType,
-    access_rule: Box<dyn Debug + 'r>,
+    access_rule: Box<dyn ViolatedRule + 'r>,
     involved_object_uses: Vec<UseRelation>,
+    locations: Vec<ViolationLocation>,
+    cycle_members: Vec<String>,
+    description: Option<String>,
+    reason: Option<String>,
+    severity: Severity,
+    rule_index: Option<usize>,
 }
 
 impl<'r> RuleViolation<'r> {
     pub fn new(
         violation_type: RuleViolationType,
-        access_rule: Box<dyn Debug + 'r>,
+        access_rule: Box<dyn ViolatedRule + 'r>,
         involved_object_uses: Vec<UseRelation>,
     ) -> Self {
         RuleViolation {
             violation_type,
             access_rule,
             involved_object_uses,
+            locations: Vec::new(),
+            cycle_members: Vec::new(),
+            description: None,
+            reason: None,
+            severity: Severity::Error,
+            rule_index: None,
         }
     }
 
+    /// Adds the locations of the violation, e.g. of a misnamed struct or an `unsafe` block
+    pub fn with_locations(mut self, locations: Vec<ViolationLocation>) -> Self {
+        self.locations = locations;
+        self
+    }
+
+    /// Adds the names of all members of a violated cycle, e.g. the paths of the modules that depend on each other.
+    /// The involved uses only form the shortest cycle through the first member.
+    pub fn with_cycle_members(mut self, cycle_members: Vec<String>) -> Self {
+        self.cycle_members = cycle_members;
+        self
+    }
+
+    /// Adds a description that explains the violation in the words of the violated rule
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Adds the reason why the violated rule exists
+    pub fn with_reason(mut self, reason: String) -> Self {
+        self.reason = Some(reason);
+        self
+    }
+
+    pub fn reason(&self) -> Option<&String> {
+        self.reason.as_ref()
+    }
+
+    /// Sets the severity of the violated rule, `Error` by default
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Sets the index of the violated rule in its `Architecture`
+    pub fn with_rule_index(mut self, rule_index: usize) -> Self {
+        self.rule_index = Some(rule_index);
+        self
+    }
+
+    /// Returns the index of the violated rule in its `Architecture`.
+    /// Violations that are not caused by a rule of the architecture, e.g. unused suppressions, have none.
+    pub fn rule_index(&self) -> Option<usize> {
+        self.rule_index
+    }
+
     pub fn violation_type(&self) -> RuleViolationType {
         self.violation_type
     }
@@ -36,103 +103,117 @@ impl<'r> RuleViolation<'r> {
         &self.involved_object_uses
     }
 
-    pub fn access_rule(&self) -> &(dyn Debug + 'r) {
-        &self.access_rule
+    pub fn locations(&self) -> &Vec<ViolationLocation> {
+        &self.locations
     }
 
-    pub fn print(&self, tree: &[ModuleNode]) {
-        match self.violation_type {
-            RuleViolationType::LayerDoNotExist => {
-                println!(
-                    "Layers specified in the rule {:?} do not match specified architecture layers.",
-                    self.access_rule
-                );
-            }
-            RuleViolationType::IncompleteLayerSpecification => {
-                println!("Layer specification is incomplete!");
-            }
-            RuleViolationType::SingleLocation => {
-                let using_object = self.involved_object_uses[0].using_object();
-                let used_object = self.involved_object_uses[0].used_object();
-                let (in_file_line_number, in_file_column_range, in_file_line) =
-                    find_text_range_in_file(
-                        tree[using_object.node_index()].file_path(),
-                        using_object.usable_object().text_range(),
-                    );
-                let (acc_file_line_number, acc_file_column_range, acc_file_line) =
-                    find_text_range_in_file(
-                        tree[used_object.node_index()].file_path(),
-                        used_object.usable_object().text_range(),
-                    );
-                println!("Violated rule     | {:?}", self.access_rule);
-                println!("-------------------");
-                println!(
-                    "Accessor file     | {}",
-                    tree[using_object.node_index()].file_path()
-                );
-                println!(
-                    "Object            | {:?}: {}@{:?}",
-                    using_object.usable_object().object_type(),
-                    using_object.usable_object().object_name(),
-                    using_object.usable_object().text_range()
-                );
-                println!(
-                    "Line in file      | ({}, {:?}): {}",
-                    in_file_line_number, in_file_column_range, in_file_line
-                );
-                println!("-------------------");
-                println!(
-                    "Accessed file     | {}",
-                    tree[used_object.node_index()].file_path()
-                );
-                println!("Object path       | {}", used_object.full_module_path());
-                println!(
-                    "Object            | {:?}: {}@{:?}",
-                    used_object.usable_object().object_type(),
-                    used_object.usable_object().object_name(),
-                    used_object.usable_object().text_range()
-                );
-                println!(
-                    "Line in file      | ({}, {:?}): {}",
-                    acc_file_line_number, acc_file_column_range, acc_file_line
-                );
-            }
-            RuleViolationType::Cycle => {
-                println!("Violated rule: {:?}", self.access_rule);
-                for use_relation in self.involved_object_uses.iter() {
-                    let using_object = use_relation.using_object();
-                    println!(
-                        " | File path:    {}",
-                        tree[using_object.node_index()].file_path()
-                    );
-                    println!(
-                        " | Accessed:     {:?}: {}@{:?}",
-                        using_object.usable_object().object_type(),
-                        using_object.usable_object().object_name(),
-                        using_object.usable_object().text_range()
-                    );
-                    println!(" ⮟ ");
-                }
-            }
+    pub fn cycle_members(&self) -> &Vec<String> {
+        &self.cycle_members
+    }
+
+    pub fn access_rule(&self) -> &(dyn ViolatedRule + 'r) {
+        self.access_rule.as_ref()
+    }
+
+    /// Returns the name of the violated rule like `MayNotAccess`, see `ViolatedRule::name`
+    pub fn rule_name(&self) -> &'static str {
+        self.access_rule.name()
+    }
+
+    pub fn description(&self) -> Option<&String> {
+        self.description.as_ref()
+    }
+
+    /// Describes the violation including the files, module paths and source spans of the involved objects.
+    /// The spans are located in the files read from `source_files`.
+    pub fn to_record(
+        &self,
+        tree: &[ModuleNode],
+        source_files: &mut SourceFiles,
+    ) -> ViolationRecord {
+        let mut record = ViolationRecord::new(
+            self.access_rule.descriptor(),
+            self.violation_type,
+            self.involved_object_uses
+                .iter()
+                .map(|use_relation| UseRecord::new(use_relation, tree, source_files))
+                .collect(),
+        )
+        .with_locations(
+            self.locations
+                .iter()
+                .map(|location| LocationRecord::new(location, tree, source_files))
+                .collect(),
+        )
+        .with_rule_description(self.access_rule.to_string())
+        .with_severity(self.severity);
+        if let Some(rule_index) = self.rule_index {
+            record = record.with_rule_index(rule_index);
+        }
+        if let Some(description) = self.description.as_ref() {
+            record = record.with_description(description.clone());
         }
+        if let Some(reason) = self.reason.as_ref() {
+            record = record.with_reason(reason.clone());
+        }
+        record
+    }
+
+    /// Renders the violation like a compiler error, see `ViolationRecord::render`
+    pub fn render(
+        &self,
+        tree: &[ModuleNode],
+        source_files: &mut SourceFiles,
+        color: bool,
+    ) -> String {
+        self.to_record(tree, source_files)
+            .render(source_files, color)
+    }
+
+    pub fn print(&self, tree: &[ModuleNode]) {
+        print!("{}", self.render(tree, &mut SourceFiles::new(), false));
     }
 }
 
-fn find_text_range_in_file(file_path: &str, text_range: &TextRange) -> (usize, TextRange, String) {
-    let file = File::open(file_path).unwrap();
-    let reader = BufReader::new(file);
-    let mut text_conquered: u32 = 0;
-    for (line_index, line) in reader.lines().enumerate() {
-        if let Ok(line) = line {
-            if TextSize::from(text_conquered + line.len() as u32) >= text_range.end() {
-                return (
-                    line_index + 1,
-                    TextRange::new(TextSize::from(1), TextSize::from(line.len() as u32)),
-                    line,
-                );
-            }
-            text_conquered += line.len() as u32;
+impl Display for RuleViolation<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.description.as_ref() {
+            Some(description) => write!(f, "{}: {}", self.severity, description)?,
+            None => write!(f, "{}: {}", self.severity, self.access_rule)?,
+        }
+        let involved_objects: Vec<String> = self
+            .involved_object_uses
+            .iter()
+            .map(|use_relation| {
+                format!(
+                    "`{}` -> `{}`",
+                    use_relation.using_object().full_module_path(),
+                    use_relation.used_object().full_module_path()
+                )
+            })
+            .chain(
+                self.locations
+                    .iter()
+                    .map(|location| format!("in `{}`", location.module_path())),
+            )
+            .collect();
+        if !involved_objects.is_empty() {
+            write!(f, " ({})", involved_objects.join(", "))?;
         }
+        if let Some(reason) = self.reason.as_ref() {
+            write!(f, " (reason: {})", reason)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for RuleViolation<'_> {}
+
+impl<'r> PartialEq for RuleViolation<'r> {
+    fn eq(&self, other: &Self) -> bool {
+        self.violation_type == other.violation_type
+            && self.involved_object_uses == other.involved_object_uses
+            && self.locations == other.locations
+            && self.description == other.description
     }
-    unreachable!()
 }
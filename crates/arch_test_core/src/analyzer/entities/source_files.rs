@@ -0,0 +1,24 @@
+use std::collections::HashMap;
+use std::fs;
+
+use crate::analyzer::domain_values::LineIndex;
+
+/// Reads source files on demand and keeps their `LineIndex`, so that each file is read and indexed only once
+#[derive(Debug, Default)]
+pub struct SourceFiles {
+    line_indices: HashMap<String, Option<LineIndex>>,
+}
+
+impl SourceFiles {
+    pub fn new() -> Self {
+        SourceFiles::default()
+    }
+
+    /// Returns the index of the file at `file_path` or `None` if it can not be read
+    pub fn line_index(&mut self, file_path: &str) -> Option<&LineIndex> {
+        self.line_indices
+            .entry(file_path.to_owned())
+            .or_insert_with(|| fs::read_to_string(file_path).ok().map(LineIndex::new))
+            .as_ref()
+    }
+}
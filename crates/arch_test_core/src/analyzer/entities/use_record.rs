@@ -0,0 +1,32 @@
+use crate::analyzer::entities::{ObjectRecord, SourceFiles};
+use crate::parser::domain_values::UseRelation;
+use crate::parser::entities::ModuleNode;
+
+/// # Serializable description of a `UseRelation` involved in a violation
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct UseRecord {
+    accessor: ObjectRecord,
+    accessed: ObjectRecord,
+}
+
+impl UseRecord {
+    pub fn new(
+        use_relation: &UseRelation,
+        tree: &[ModuleNode],
+        source_files: &mut SourceFiles,
+    ) -> Self {
+        UseRecord {
+            accessor: ObjectRecord::new(use_relation.using_object(), tree, source_files),
+            accessed: ObjectRecord::new(use_relation.used_object(), tree, source_files),
+        }
+    }
+
+    pub fn accessor(&self) -> &ObjectRecord {
+        &self.accessor
+    }
+
+    pub fn accessed(&self) -> &ObjectRecord {
+        &self.accessed
+    }
+}
@@ -0,0 +1,359 @@
+use crate::analyzer::domain_values::{RuleDescriptor, RuleViolationType, Severity, SourceSpan};
+use crate::analyzer::entities::{LocationRecord, ObjectRecord, SourceFiles, UseRecord};
+
+const ERROR_STYLE: &str = "1;31";
+const WARNING_STYLE: &str = "1;33";
+const GUTTER_STYLE: &str = "1;34";
+const MESSAGE_STYLE: &str = "1";
+
+/// # Serializable description of a `RuleViolation`
+/// In contrast to the violation itself, it contains the files, module paths and source spans of the involved objects
+/// and locations, see `RuleViolation::to_record`.
+/// With the feature `serde` it can be serialized, e.g. to JSON.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ViolationRecord {
+    rule: RuleDescriptor,
+    rule_description: Option<String>,
+    rule_index: Option<usize>,
+    violation_type: RuleViolationType,
+    severity: Severity,
+    description: Option<String>,
+    reason: Option<String>,
+    uses: Vec<UseRecord>,
+    locations: Vec<LocationRecord>,
+}
+
+impl ViolationRecord {
+    pub fn new(
+        rule: RuleDescriptor,
+        violation_type: RuleViolationType,
+        uses: Vec<UseRecord>,
+    ) -> Self {
+        ViolationRecord {
+            rule,
+            rule_description: None,
+            rule_index: None,
+            violation_type,
+            severity: Severity::Error,
+            description: None,
+            reason: None,
+            uses,
+            locations: Vec::new(),
+        }
+    }
+
+    pub fn with_locations(mut self, locations: Vec<LocationRecord>) -> Self {
+        self.locations = locations;
+        self
+    }
+
+    /// Adds the sentence that describes the violated rule, e.g. "modules in `parser` may not access `analyzer`"
+    pub fn with_rule_description(mut self, rule_description: String) -> Self {
+        self.rule_description = Some(rule_description);
+        self
+    }
+
+    pub fn with_rule_index(mut self, rule_index: usize) -> Self {
+        self.rule_index = Some(rule_index);
+        self
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    pub fn with_reason(mut self, reason: String) -> Self {
+        self.reason = Some(reason);
+        self
+    }
+
+    pub fn rule(&self) -> &RuleDescriptor {
+        &self.rule
+    }
+
+    pub fn rule_description(&self) -> Option<&String> {
+        self.rule_description.as_ref()
+    }
+
+    pub fn rule_index(&self) -> Option<usize> {
+        self.rule_index
+    }
+
+    pub fn violation_type(&self) -> RuleViolationType {
+        self.violation_type
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    pub fn description(&self) -> Option<&String> {
+        self.description.as_ref()
+    }
+
+    pub fn reason(&self) -> Option<&String> {
+        self.reason.as_ref()
+    }
+
+    pub fn uses(&self) -> &Vec<UseRecord> {
+        &self.uses
+    }
+
+    pub fn locations(&self) -> &Vec<LocationRecord> {
+        &self.locations
+    }
+
+    /// Describes the violation in a single sentence, i.e. the description of the violated rule if there is one
+    pub fn message(&self) -> String {
+        match (self.description.as_ref(), self.uses.first()) {
+            (Some(description), _) => description.clone(),
+            (None, Some(use_record)) => format!(
+                "`{}` accesses `{}`",
+                use_record.accessor().module_path(),
+                use_record.accessed().object_path()
+            ),
+            (None, None) => match self.violation_type {
+                RuleViolationType::LayerDoNotExist => format!(
+                    "The rule `{}` refers to layers that are not part of the architecture",
+                    self.rule.kind()
+                ),
+                RuleViolationType::IncompleteLayerSpecification => {
+                    "Not every module is covered by a layer".to_owned()
+                }
+                _ => format!("The rule `{}` is violated", self.rule.kind()),
+            },
+        }
+    }
+
+    /// Renders the violation like a compiler error: A headline with the severity and the violated rule,
+    /// followed by the source lines of the involved objects with carets under their exact ranges and notes.
+    /// The rule is noted by its description if there is one, otherwise by its kind and parameters.
+    /// The accessor is the primary location, the accessed object or the further hops of a cycle are secondary locations.
+    /// The locations of a violation that is not caused by a use, e.g. of an `unsafe` block, are primary locations.
+    /// The source lines are read from `source_files`. If `color` is set, the output is colored with ANSI escape codes.
+    ///
+    /// Example:
+    /// ```text
+    /// error[MayNotAccess]: `crate::domain` accesses `crate::infrastructure::Database`
+    ///  --> src/domain.rs:1:5
+    ///   |
+    /// 1 | use crate::infrastructure::Database;
+    ///   |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ accessed here
+    ///   |
+    ///  ::: src/infrastructure.rs:1:12
+    ///   |
+    /// 1 | pub struct Database;
+    ///   |            -------- `Database` is defined here
+    ///   |
+    ///   = rule: modules in `domain` may not access `infrastructure` (within the same parent)
+    /// ```
+    pub fn render(&self, source_files: &mut SourceFiles, color: bool) -> String {
+        let severity_style = match self.severity {
+            Severity::Warning => WARNING_STYLE,
+            _ => ERROR_STYLE,
+        };
+        let (labels, mut notes) = self.labels();
+        notes.push(match self.rule_description.as_ref() {
+            Some(rule_description) => format!("rule: {}", rule_description),
+            None => format!("rule: {}", self.rule),
+        });
+        if let Some(reason) = self.reason.as_ref() {
+            notes.push(format!("reason: {}", reason));
+        }
+        let gutter_width = labels
+            .iter()
+            .filter_map(|label| label.span)
+            .map(|span| span.start_line().to_string().len())
+            .max()
+            .unwrap_or(1);
+        let padding = " ".repeat(gutter_width);
+        let gutter = paint(&format!("{} |", padding), GUTTER_STYLE, color);
+
+        let mut output = format!(
+            "{}{}\n",
+            paint(
+                &format!("{}[{}]", self.severity, self.code()),
+                severity_style,
+                color
+            ),
+            paint(&format!(": {}", self.message()), MESSAGE_STYLE, color)
+        );
+        for (label_index, label) in labels.iter().enumerate() {
+            if label_index > 0 {
+                output.push_str(&format!("{}\n", gutter));
+            }
+            let arrow = if label_index == 0 { "-->" } else { ":::" };
+            output.push_str(&format!(
+                "{}{} {}\n",
+                padding,
+                paint(arrow, GUTTER_STYLE, color),
+                label.position()
+            ));
+            let line_index = label.file.and_then(|file| source_files.line_index(file));
+            if let (Some(span), Some(line_index)) = (label.span, line_index) {
+                let line = line_index.line(span.start_line()).unwrap_or_default();
+                let marked_characters = if span.end_line() == span.start_line() {
+                    span.end_column().saturating_sub(span.start_column())
+                } else {
+                    line.chars().count() + 1 - span.start_column()
+                };
+                let (marker, marker_style) = if label.is_primary {
+                    ("^", severity_style)
+                } else {
+                    ("-", GUTTER_STYLE)
+                };
+                let markers = marker.repeat(
+                    display_width(
+                        line.chars()
+                            .skip(span.start_column() - 1)
+                            .take(marked_characters),
+                    )
+                    .max(1),
+                );
+                let annotation = format!("{} {}", markers, label.text);
+                output.push_str(&format!("{}\n", gutter));
+                output.push_str(&format!(
+                    "{} {}\n",
+                    paint(
+                        &format!("{:>width$} |", span.start_line(), width = gutter_width),
+                        GUTTER_STYLE,
+                        color
+                    ),
+                    line.replace('\t', "    ")
+                ));
+                output.push_str(&format!(
+                    "{} {}{}\n",
+                    gutter,
+                    " ".repeat(display_width(line.chars().take(span.start_column() - 1))),
+                    paint(annotation.trim_end(), marker_style, color)
+                ));
+            }
+        }
+        output.push_str(&format!("{}\n", gutter));
+        for note in notes {
+            output.push_str(&format!(
+                "{} {}\n",
+                paint(&format!("{} =", padding), GUTTER_STYLE, color),
+                note
+            ));
+        }
+        output
+    }
+
+    /// Returns the code of the headline, i.e. the kind of the violated rule or the kind of violation if it is not caused by a rule
+    fn code(&self) -> String {
+        match self.violation_type {
+            RuleViolationType::SingleLocation
+            | RuleViolationType::SingleObject
+            | RuleViolationType::Cycle => self.rule.kind().clone(),
+            violation_type => format!("{:?}", violation_type),
+        }
+    }
+
+    /// Returns the labeled objects and locations and notes about objects without a location
+    fn labels(&self) -> (Vec<Label<'_>>, Vec<String>) {
+        let mut labels = Vec::new();
+        let mut notes = Vec::new();
+        match self.violation_type {
+            RuleViolationType::SingleLocation => {
+                for use_record in self.uses.iter() {
+                    labels.push(Label::object(
+                        use_record.accessor(),
+                        true,
+                        "accessed here".to_owned(),
+                    ));
+                    if use_record.accessed().file().is_some() {
+                        labels.push(Label::object(
+                            use_record.accessed(),
+                            false,
+                            format!("`{}` is defined here", use_record.accessed().object_name()),
+                        ));
+                    } else {
+                        notes.push(format!(
+                            "`{}` belongs to an external crate",
+                            use_record.accessed().object_path()
+                        ));
+                    }
+                }
+            }
+            RuleViolationType::Cycle => {
+                for (use_index, use_record) in self.uses.iter().enumerate() {
+                    labels.push(Label::object(
+                        use_record.accessor(),
+                        use_index == 0,
+                        format!("uses `{}`", use_record.accessed().object_path()),
+                    ));
+                }
+            }
+            _ => {
+                for use_record in self.uses.iter() {
+                    labels.push(Label::object(use_record.accessor(), true, String::new()));
+                }
+            }
+        }
+        for location in self.locations.iter() {
+            labels.push(Label {
+                file: Some(location.file()),
+                module_path: location.module_path(),
+                span: location.span(),
+                is_primary: true,
+                text: String::new(),
+            });
+        }
+        (labels, notes)
+    }
+}
+
+/// A labeled object or location of a rendered violation, the primary ones are marked with carets
+struct Label<'a> {
+    file: Option<&'a String>,
+    module_path: &'a String,
+    span: Option<SourceSpan>,
+    is_primary: bool,
+    text: String,
+}
+
+impl<'a> Label<'a> {
+    fn object(object: &'a ObjectRecord, is_primary: bool, text: String) -> Self {
+        Label {
+            file: object.file(),
+            module_path: object.module_path(),
+            span: object.span(),
+            is_primary,
+            text,
+        }
+    }
+
+    /// Returns the position like `src/main.rs:1:5`, or the module path if there is no file
+    fn position(&self) -> String {
+        match (self.file, self.span) {
+            (Some(file), Some(span)) => {
+                format!("{}:{}:{}", file, span.start_line(), span.start_column())
+            }
+            (Some(file), None) => file.clone(),
+            _ => self.module_path.clone(),
+        }
+    }
+}
+
+/// Returns the number of columns the `characters` occupy, tabs are expanded to four spaces
+fn display_width(characters: impl Iterator<Item = char>) -> usize {
+    characters
+        .map(|character| if character == '\t' { 4 } else { 1 })
+        .sum()
+}
+
+fn paint(text: &str, style: &str, color: bool) -> String {
+    if color {
+        format!("\x1b[{}m{}\x1b[0m", style, text)
+    } else {
+        text.to_owned()
+    }
+}
@@ -0,0 +1,129 @@
+use crate::analyzer::domain_values::Severity;
+use crate::analyzer::entities::{RuleViolation, SourceFiles};
+use crate::parser::entities::ModuleNode;
+
+/// Collects the violations of all access rules of an `Architecture`.
+/// The violations are grouped by the violated rule, in the order the rules were registered.
+/// Within a group, each violation is only contained once.
+/// Violations of rules with the severity `Warning` are contained as well, see `has_errors`.
+#[derive(Debug, Default)]
+pub struct ViolationReport<'r> {
+    groups: Vec<Vec<RuleViolation<'r>>>,
+}
+
+impl<'r> ViolationReport<'r> {
+    pub fn new() -> Self {
+        ViolationReport::default()
+    }
+
+    /// Adds the violations of a single rule as a new group. Duplicates are removed and empty groups are skipped.
+    pub fn add_group(&mut self, violations: Vec<RuleViolation<'r>>) {
+        let mut group: Vec<RuleViolation<'r>> = Vec::with_capacity(violations.len());
+        for violation in violations {
+            if !group.contains(&violation) {
+                group.push(violation);
+            }
+        }
+        group.sort_by_key(|violation| {
+            let use_position = violation
+                .involved_object_uses()
+                .first()
+                .map(|use_relation| {
+                    (
+                        use_relation.using_object().node_index(),
+                        use_relation
+                            .using_object()
+                            .usable_object()
+                            .text_range()
+                            .start(),
+                    )
+                });
+            let location_position = violation
+                .locations()
+                .first()
+                .map(|location| (location.node_index(), location.text_range().start()));
+            use_position.or(location_position).unwrap_or_default()
+        });
+        if !group.is_empty() {
+            self.groups.push(group);
+        }
+    }
+
+    /// Keeps only the violations for which `keep` returns `true`. Groups that become empty are removed.
+    pub fn retain(&mut self, mut keep: impl FnMut(&RuleViolation<'r>) -> bool) {
+        for group in self.groups.iter_mut() {
+            group.retain(|violation| keep(violation));
+        }
+        self.groups.retain(|group| !group.is_empty());
+    }
+
+    pub fn groups(&self) -> &Vec<Vec<RuleViolation<'r>>> {
+        &self.groups
+    }
+
+    pub fn violations(&self) -> impl Iterator<Item = &RuleViolation<'r>> {
+        self.groups.iter().flatten()
+    }
+
+    pub fn len(&self) -> usize {
+        self.groups.iter().map(|group| group.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Returns `true` if any violation has the severity `Error`
+    pub fn has_errors(&self) -> bool {
+        self.violations()
+            .any(|violation| violation.severity() == Severity::Error)
+    }
+
+    /// Returns the number of violations with the severity `Warning`
+    pub fn warning_count(&self) -> usize {
+        self.violations()
+            .filter(|violation| violation.severity() == Severity::Warning)
+            .count()
+    }
+
+    pub fn print(&self, tree: &[ModuleNode]) {
+        print!("{}", self.render(tree, false));
+    }
+
+    /// Renders the violations of each rule under a header with their count and the violated rule,
+    /// every violation like a compiler error, see `ViolationRecord::render`, followed by a summary.
+    /// Each source file is read only once. If `color` is set, the output is colored with ANSI escape codes.
+    pub fn render(&self, tree: &[ModuleNode], color: bool) -> String {
+        let mut source_files = SourceFiles::new();
+        let mut output = String::new();
+        for group in self.groups.iter() {
+            output.push_str(&format!(
+                "{} {} of {}: {}\n",
+                group.len(),
+                if group[0].severity() == Severity::Warning {
+                    "warning(s)"
+                } else {
+                    "violation(s)"
+                },
+                group[0].rule_name(),
+                group[0].access_rule()
+            ));
+            output.push_str("===================\n");
+            for violation in group.iter() {
+                output.push_str(&violation.render(tree, &mut source_files, color));
+                output.push('\n');
+            }
+        }
+        let warning_count = self.warning_count();
+        if warning_count > 0 {
+            output.push_str(&format!(
+                "Found {} violation(s) in total, {} of them are warnings.\n",
+                self.len(),
+                warning_count
+            ));
+        } else {
+            output.push_str(&format!("Found {} violation(s) in total.\n", self.len()));
+        }
+        output
+    }
+}
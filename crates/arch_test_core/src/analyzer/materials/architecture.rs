@@ -1,12 +1,24 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
 
-use crate::analyzer::domain_values::RuleViolationType;
-use crate::analyzer::entities::RuleViolation;
-use crate::analyzer::services::AccessRule;
+use itertools::Itertools;
+
+use crate::analyzer::domain_values::{
+    LayerSelector, RuleRecord, RuleViolationType, Severity, ViolatedRule,
+};
+use crate::analyzer::entities::{RuleViolation, ViolationReport};
+use crate::analyzer::services::{suppressing, unused_suppression, AccessRule};
 use crate::parser::entities::ModuleNode;
 use crate::parser::materials::ModuleTree;
 
 /// This is the central object that holds the architecture rules and executes them
+/// The layer names are interpreted as `LayerSelector`s.
+/// External crates can be declared as layers using `with_external_layer`.
+/// Violations within the scope of a suppression of the violated rule, e.g. `// archtest-allow(MayNotAccess): reason`, are skipped.
+/// Suppressions that do not suppress any violation are reported as `UnusedSuppression` violations with the severity of the suppressed rule,
+/// suppressions of rules that are not part of the architecture are reported as errors.
+/// Each access rule has a `Severity`: Violations of `Warning` rules are reported without failing `check_access_rules`
+/// and `Off` rules are not checked at all.
 ///
 /// Example:
 /// ```ignore
@@ -14,6 +26,7 @@ use crate::parser::materials::ModuleTree;
 /// .with_access_rule(NoParentAccess)
 /// .with_access_rule(NoModuleCyclicDependencies)
 /// .with_access_rule(NoLayerCyclicDependencies)
+/// .with_external_layer("infrastructure".to_owned(), hash_set!["diesel".to_owned(), "sqlx".to_owned()])
 /// ...
 /// .with_access_rule(MayNotAccess::new(
 ///     "materials".to_owned(),
@@ -24,60 +37,285 @@ use crate::parser::materials::ModuleTree;
 #[derive(Debug)]
 pub struct Architecture<'r> {
     layer_names: HashSet<String>,
-    access_rules: Vec<Box<dyn AccessRule + 'r>>,
+    external_layers: HashMap<String, HashSet<String>>,
+    access_rules: Vec<(Box<dyn AccessRule + 'r>, Severity)>,
 }
 
 impl<'r> Architecture<'r> {
     pub fn new(layer_names: HashSet<String>) -> Self {
         Architecture {
             layer_names,
+            external_layers: HashMap::default(),
             access_rules: Vec::default(),
         }
     }
 
-    pub fn with_access_rule(mut self, access_rule: impl AccessRule + 'r) -> Self {
-        self.access_rules.push(Box::new(access_rule));
+    /// Adds the `access_rule` with the severity `Error`
+    pub fn with_access_rule(self, access_rule: impl AccessRule + 'r) -> Self {
+        self.with_access_rule_and_severity(access_rule, Severity::Error)
+    }
+
+    pub fn with_access_rule_and_severity(
+        mut self,
+        mut access_rule: impl AccessRule + 'r,
+        severity: Severity,
+    ) -> Self {
+        access_rule.resolve_external_layers(&self.external_layers);
+        self.access_rules.push((Box::new(access_rule), severity));
+        self
+    }
+
+    /// Declares the external crates `crate_names` as a layer named `layer_name`.
+    /// Rules can refer to the layer name as well as to the names of the crates.
+    pub fn with_external_layer(mut self, layer_name: String, crate_names: HashSet<String>) -> Self {
+        self.external_layers.insert(layer_name, crate_names);
+        for (access_rule, _) in self.access_rules.iter_mut() {
+            access_rule.resolve_external_layers(&self.external_layers);
+        }
         self
     }
 
-    pub fn validate_access_rules(&'r self) -> Result<(), RuleViolation> {
-        for access_rule in self.access_rules.iter() {
-            if !access_rule.validate(&self.layer_names) {
+    /// Fails if an access rule refers to a layer that is not part of the architecture,
+    /// or to a layer whose name is a `regex:` selector with an invalid regular expression.
+    pub fn validate_access_rules(&'r self) -> Result<(), RuleViolation<'r>> {
+        let mut layer_names = self.layer_names.clone();
+        for (layer_name, crate_names) in self.external_layers.iter() {
+            layer_names.insert(layer_name.clone());
+            layer_names.extend(crate_names.iter().cloned());
+        }
+        let invalid_layers: Vec<&String> = self
+            .layer_names
+            .iter()
+            .filter(|layer| LayerSelector::parse(layer).is_err())
+            .sorted()
+            .collect();
+        for (rule_index, (access_rule, _)) in self.access_rules.iter().enumerate() {
+            if !access_rule.validate(&layer_names) {
                 return Err(RuleViolation::new(
                     RuleViolationType::LayerDoNotExist,
                     Box::new(access_rule),
                     vec![],
-                ));
+                )
+                .with_rule_index(rule_index));
+            }
+            for invalid_layer in invalid_layers.iter() {
+                let mut other_layers = layer_names.clone();
+                other_layers.remove(*invalid_layer);
+                if !access_rule.validate(&other_layers) {
+                    return Err(RuleViolation::new(
+                        RuleViolationType::LayerDoNotExist,
+                        Box::new(access_rule),
+                        vec![],
+                    )
+                    .with_description(format!(
+                        "The layer selector `{}` is no valid regular expression",
+                        invalid_layer
+                    ))
+                    .with_rule_index(rule_index));
+                }
             }
         }
         Ok(())
     }
 
-    pub fn check_access_rules(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
-        for access_rule in self.access_rules.iter() {
-            access_rule.check(module_tree)?;
+    /// Returns the first violation of an access rule with the severity `Error`.
+    /// Rules with the severity `Warning` or `Off` are not checked.
+    pub fn check_access_rules(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation<'_>> {
+        let mut used_suppressions = HashSet::new();
+        for (rule_index, (access_rule, _)) in self
+            .access_rules
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, severity))| *severity == Severity::Error)
+        {
+            if let Some(violation) =
+                unsuppressed_violations(access_rule.as_ref(), module_tree, &mut used_suppressions)
+                    .into_iter()
+                    .next()
+            {
+                return Err(violation.with_rule_index(rule_index));
+            }
         }
-        Ok(())
+        let rule_severities = self.rule_severities(&[Severity::Error]);
+        match unused_suppressions(module_tree, &used_suppressions, &rule_severities)
+            .into_iter()
+            .next()
+        {
+            Some(violation) => Err(violation),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks all access rules and collects every violation instead of stopping at the first one.
+    /// The report contains the violations of rules with the severity `Warning` as well, see `ViolationReport::has_errors`.
+    pub fn check_all_access_rules(
+        &self,
+        module_tree: &ModuleTree,
+    ) -> Result<(), ViolationReport<'_>> {
+        let mut report = ViolationReport::new();
+        let mut used_suppressions = HashSet::new();
+        for (rule_index, (access_rule, severity)) in self
+            .access_rules
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, severity))| *severity != Severity::Off)
+        {
+            report.add_group(
+                unsuppressed_violations(access_rule.as_ref(), module_tree, &mut used_suppressions)
+                    .into_iter()
+                    .map(|violation| {
+                        violation
+                            .with_severity(*severity)
+                            .with_rule_index(rule_index)
+                    })
+                    .collect(),
+            );
+        }
+        let rule_severities = self.rule_severities(&[Severity::Error, Severity::Warning]);
+        report.add_group(unused_suppressions(
+            module_tree,
+            &used_suppressions,
+            &rule_severities,
+        ));
+        if report.is_empty() {
+            return Ok(());
+        }
+        Err(report)
     }
 
     pub fn check_complete_layer_specification(
         &self,
         module_tree: &ModuleTree,
-    ) -> Result<(), RuleViolation> {
+    ) -> Result<(), RuleViolation<'_>> {
         let tree: &Vec<ModuleNode> = module_tree.tree();
+        let layer_selectors: Vec<LayerSelector> = self
+            .layer_names
+            .iter()
+            .map(|layer| LayerSelector::from_layer_name(layer))
+            .collect();
         if tree.iter().any(|node| {
             node.parent_index().is_some()
-                && !self.layer_names.contains(node.module_name())
-                && !self
-                    .layer_names
-                    .contains(tree[node.parent_index().unwrap()].module_name())
+                && !layer_selectors.iter().any(|selector| {
+                    selector.matches_path(&node.get_fully_qualified_path(tree))
+                        || selector.matches_path(
+                            &tree[node.parent_index().unwrap()].get_fully_qualified_path(tree),
+                        )
+                })
         }) {
             return Err(RuleViolation::new(
                 RuleViolationType::IncompleteLayerSpecification,
-                Box::new(()),
+                Box::new(CompleteLayerSpecification),
                 vec![],
             ));
         }
         Ok(())
     }
+
+    /// Describes the access rules in the order they were added, see `ArchitectureReport::with_rules`
+    pub fn rule_records(&self) -> Vec<RuleRecord> {
+        self.access_rules
+            .iter()
+            .enumerate()
+            .map(|(rule_index, (access_rule, severity))| {
+                RuleRecord::new(
+                    rule_index,
+                    access_rule.descriptor(),
+                    *severity,
+                    access_rule.to_string(),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the most severe severity of the rules per rule name. Severities that are not `checked` are considered to be `Off`.
+    fn rule_severities(&self, checked: &[Severity]) -> HashMap<&'static str, Severity> {
+        let mut rule_severities = HashMap::new();
+        for (access_rule, severity) in self.access_rules.iter() {
+            let severity = if checked.contains(severity) {
+                *severity
+            } else {
+                Severity::Off
+            };
+            let rule_severity = rule_severities
+                .entry(access_rule.name())
+                .or_insert(Severity::Off);
+            if severity == Severity::Error || *rule_severity == Severity::Off {
+                *rule_severity = severity;
+            }
+        }
+        rule_severities
+    }
+}
+
+/// Returns the violations of the `access_rule` that are not suppressed and records the suppressions that silenced the others.
+/// A suppression may refer to the `access_rule` or to the rule that is named in the violation, e.g. a rule a `FluentRule` compiles down to.
+fn unsuppressed_violations<'r>(
+    access_rule: &'r dyn AccessRule,
+    module_tree: &ModuleTree,
+    used_suppressions: &mut HashSet<(usize, usize)>,
+) -> Vec<RuleViolation<'r>> {
+    let rule_name = access_rule.name();
+    access_rule
+        .check_all(module_tree)
+        .into_iter()
+        .filter(|violation| {
+            let mut suppressions = suppressing(violation, rule_name, module_tree.tree());
+            if violation.rule_name() != rule_name {
+                suppressions.extend(suppressing(
+                    violation,
+                    violation.rule_name(),
+                    module_tree.tree(),
+                ));
+            }
+            let is_suppressed = !suppressions.is_empty();
+            used_suppressions.extend(suppressions);
+            !is_suppressed
+        })
+        .collect()
+}
+
+/// Returns a violation for each suppression in the `module_tree` that is not within the `used_suppressions`.
+/// The violation has the severity of the suppressed rule in `rule_severities`.
+/// Suppressions of rules that are `Off` are skipped, suppressions of unknown rules are reported as errors.
+fn unused_suppressions(
+    module_tree: &ModuleTree,
+    used_suppressions: &HashSet<(usize, usize)>,
+    rule_severities: &HashMap<&'static str, Severity>,
+) -> Vec<RuleViolation<'static>> {
+    let tree = module_tree.tree();
+    tree.iter()
+        .flat_map(|node| {
+            (0..node.suppressions().len())
+                .map(move |suppression_index| (node.index(), suppression_index))
+        })
+        .filter(|suppression| !used_suppressions.contains(suppression))
+        .filter_map(|(node_index, suppression_index)| {
+            let rule_name = tree[node_index].suppressions()[suppression_index].rule_name();
+            let violation = unused_suppression(node_index, suppression_index, tree);
+            match rule_severities.get(rule_name.as_str()) {
+                Some(Severity::Off) => None,
+                Some(severity) => Some(violation.with_severity(*severity)),
+                None => Some(violation.with_description(format!(
+                    "The suppression refers to the unknown rule `{}`",
+                    rule_name
+                ))),
+            }
+        })
+        .collect()
+}
+
+/// The requirement that every module belongs to a layer, which is violated by an `IncompleteLayerSpecification`
+#[derive(Debug)]
+struct CompleteLayerSpecification;
+
+impl Display for CompleteLayerSpecification {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "every module has to belong to a layer")
+    }
+}
+
+impl ViolatedRule for CompleteLayerSpecification {
+    fn name(&self) -> &'static str {
+        "CompleteLayerSpecification"
+    }
 }
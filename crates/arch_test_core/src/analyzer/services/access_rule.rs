@@ -1,225 +1,378 @@
-use std::collections::HashSet;
-use std::fmt::Debug;
+use std::collections::{HashMap, HashSet};
 
-use velcro::hash_set;
+use itertools::Itertools;
 
 use crate::analyzer::domain_values::access_rules::{
-    MayNotAccess, MayNotBeAccessedBy, MayOnlyAccess, MayOnlyBeAccessedBy,
-    NoLayerCyclicDependencies, NoModuleCyclicDependencies, NoParentAccess,
+    ConditionGroup, FluentRule, ForbiddenPaths, ItemMayNotAccess, ItemMayOnlyBeAccessedBy,
+    LayeredArchitecture, MayNotAccess, MayNotBeAccessedBy, MayOnlyAccess, MayOnlyBeAccessedBy,
+    NamingConvention, NoLayerCyclicDependencies, NoModuleCyclicDependencies,
+    NoNamedLayerCyclicDependencies, NoParentAccess, OnionArchitecture, UnsafeOnlyIn,
+    VisibilityRule,
 };
-use crate::analyzer::domain_values::RuleViolationType;
-use crate::analyzer::entities::RuleViolation;
-use crate::analyzer::services::cyclic_dependency::{
-    contains_cyclic_dependency, contains_cyclic_dependency_on_any_level,
+use crate::analyzer::domain_values::{
+    matches_segment, quoted_list, CycleGroup, ItemSelector, LayerSelector, RuleViolationType,
+    ViolatedRule, ViolationLocation,
 };
+use crate::analyzer::entities::RuleViolation;
+use crate::analyzer::services::cyclic_dependency::{layer_cycles, level_cycles, module_cycles};
+use crate::parser::domain_values::{ItemScope, ObjectType, ObjectUse, UsableObject};
 use crate::parser::entities::ModuleNode;
 use crate::parser::materials::ModuleTree;
 use std::collections::hash_map::RandomState;
 
-pub trait AccessRule: Debug {
-    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation>;
+/// # Access rule
+/// An access rule is checked against the `ModuleTree` of the analyzed project.
+/// Implement this trait in order to define project specific rules and register them using `Architecture::with_access_rule`.
+/// Every access rule is a `ViolatedRule`, i.e. it has a `name` suppressions refer to
+/// and `Display` describes it in a sentence for humans, e.g. "modules in `parser` may not access `analyzer`".
+pub trait AccessRule: ViolatedRule {
+    /// Returns the violation of this rule, if one is found in the `module_tree`.
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation<'_>>;
+    /// Returns all violations of this rule that are found in the `module_tree`.
+    /// By default only the violation found by `check` is returned.
+    fn check_all(&self, module_tree: &ModuleTree) -> Vec<RuleViolation<'_>> {
+        self.check(module_tree).err().into_iter().collect()
+    }
+    /// Returns `true` if all layers this rule refers to are part of the specified `layer_names`.
     fn validate(&self, layer_names: &HashSet<String>) -> bool;
+    /// Lets the layers of this rule match the crates of the `external_layers` they name, see `Architecture::with_external_layer`.
+    /// By default nothing is resolved, i.e. the rule only matches external crates by their own name.
+    fn resolve_external_layers(&mut self, _external_layers: &HashMap<String, HashSet<String>>) {}
+}
+
+impl AccessRule for FluentRule {
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation<'_>> {
+        first_violation(self.check_all(module_tree))
+    }
+
+    fn check_all(&self, module_tree: &ModuleTree) -> Vec<RuleViolation<'_>> {
+        let group_violations: Vec<Vec<(usize, RuleViolation<'_>)>> = self
+            .condition_groups()
+            .iter()
+            .map(|condition_group| condition_group_violations(condition_group, module_tree))
+            .collect();
+        let violating_modules = group_violations
+            .iter()
+            .map(|violations| {
+                violations
+                    .iter()
+                    .map(|(node_index, _)| *node_index)
+                    .collect::<HashSet<usize>>()
+            })
+            .reduce(|violating_modules, other| &violating_modules & &other)
+            .unwrap_or_default();
+        group_violations
+            .into_iter()
+            .flatten()
+            .filter(|(node_index, _)| violating_modules.contains(node_index))
+            .map(|(_, violation)| {
+                let violation = violation.with_description(self.description());
+                match self.reason() {
+                    Some(reason) => violation.with_reason(reason.clone()),
+                    None => violation,
+                }
+            })
+            .collect()
+    }
+
+    fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
+        !self.layers().is_empty()
+            && self
+                .layers()
+                .iter()
+                .all(|layer| layer_names.contains(layer))
+            && self.condition_groups().iter().all(|condition_group| {
+                !condition_group.conditions().is_empty()
+                    && condition_group
+                        .may_only_access()
+                        .iter()
+                        .all(|access_rule| access_rule.validate(layer_names))
+                    && condition_group
+                        .may_not_access()
+                        .iter()
+                        .all(|access_rule| access_rule.validate(layer_names))
+                    && condition_group
+                        .may_only_be_accessed_by()
+                        .iter()
+                        .all(|access_rule| access_rule.validate(layer_names))
+                    && condition_group
+                        .may_not_be_accessed_by()
+                        .iter()
+                        .all(|access_rule| access_rule.validate(layer_names))
+            })
+    }
+
+    fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        FluentRule::resolve_external_layers(self, external_layers);
+    }
+}
+
+/// Returns the violations of the conditions of the `condition_group`, each with the index of the selected module that violates it,
+/// i.e. the accessor for `only_access` and `not_access` and the accessed module for `only_be_accessed_by` and `not_be_accessed_by`.
+fn condition_group_violations<'r>(
+    condition_group: &'r ConditionGroup,
+    module_tree: &ModuleTree,
+) -> Vec<(usize, RuleViolation<'r>)> {
+    let accessor = |violation: RuleViolation<'r>| {
+        (
+            violation.involved_object_uses()[0]
+                .using_object()
+                .node_index(),
+            violation,
+        )
+    };
+    let accessed = |violation: RuleViolation<'r>| {
+        (
+            violation.involved_object_uses()[0]
+                .used_object()
+                .node_index(),
+            violation,
+        )
+    };
+    let mut violations = Vec::new();
+    for access_rule in condition_group.may_only_access() {
+        violations.extend(access_rule.check_all(module_tree).into_iter().map(accessor));
+    }
+    for access_rule in condition_group.may_not_access() {
+        violations.extend(access_rule.check_all(module_tree).into_iter().map(accessor));
+    }
+    for access_rule in condition_group.may_only_be_accessed_by() {
+        violations.extend(access_rule.check_all(module_tree).into_iter().map(accessed));
+    }
+    for access_rule in condition_group.may_not_be_accessed_by() {
+        violations.extend(access_rule.check_all(module_tree).into_iter().map(accessed));
+    }
+    violations
 }
 
 impl AccessRule for MayOnlyAccess {
-    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
-        for node in module_tree.tree().iter().filter(|node| {
-            node.module_name() == self.accessor()
-                || has_parent_matching_name(
-                    &hash_set![self.accessor().clone()],
-                    node.index(),
-                    module_tree.tree(),
-                )
-        }) {
-            if let Some(use_relation) = node
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation<'_>> {
+        first_violation(self.check_all(module_tree))
+    }
+
+    fn check_all(&self, module_tree: &ModuleTree) -> Vec<RuleViolation<'_>> {
+        let mut violations = Vec::new();
+        for node in module_tree
+            .tree()
+            .iter()
+            .filter(|node| matches_layer(self.accessor(), node.index(), module_tree.tree()))
+        {
+            for use_relation in node
                 .use_relations(module_tree.tree(), module_tree.possible_uses(), false)
-                .iter()
-                .find(|use_relation| {
-                    !self.accessed().contains(
-                        module_tree.tree()[use_relation.used_object().node_index()].module_name(),
-                    ) && !has_parent_matching_name(
+                .into_iter()
+                .filter(|use_relation| {
+                    !matches_any(
                         self.accessed(),
                         use_relation.used_object().node_index(),
                         module_tree.tree(),
+                    ) && !is_undeclared_external(
+                        use_relation.used_object().node_index(),
+                        module_tree.tree(),
+                        self.declared_external_crates(),
                     ) && (!self.when_same_parent()
                         || module_tree.tree()[use_relation.used_object().node_index()]
                             .parent_index()
                             == node.parent_index())
                 })
             {
-                return Err(RuleViolation::new(
+                violations.push(RuleViolation::new(
                     RuleViolationType::SingleLocation,
                     Box::new(self.clone()),
-                    vec![use_relation.clone()],
+                    vec![use_relation],
                 ));
             }
         }
-        Ok(())
+        violations
     }
 
     fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
-        layer_names.contains(self.accessor())
+        layer_names.contains(self.accessor().as_str())
             && self
                 .accessed()
                 .iter()
-                .all(|layer| layer_names.contains(layer))
+                .all(|layer| layer_names.contains(layer.as_str()))
+    }
+
+    fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        MayOnlyAccess::resolve_external_layers(self, external_layers);
     }
 }
 
 impl AccessRule for MayNotAccess {
-    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
-        for node in module_tree.tree().iter().filter(|node| {
-            node.module_name() == self.accessor()
-                || has_parent_matching_name(
-                    &hash_set![self.accessor().clone()],
-                    node.index(),
-                    module_tree.tree(),
-                )
-        }) {
-            if let Some(use_relation) = node
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation<'_>> {
+        first_violation(self.check_all(module_tree))
+    }
+
+    fn check_all(&self, module_tree: &ModuleTree) -> Vec<RuleViolation<'_>> {
+        let mut violations = Vec::new();
+        for node in module_tree
+            .tree()
+            .iter()
+            .filter(|node| matches_layer(self.accessor(), node.index(), module_tree.tree()))
+        {
+            for use_relation in node
                 .use_relations(module_tree.tree(), module_tree.possible_uses(), false)
-                .iter()
-                .find(|use_relation| {
-                    (self.accessed().contains(
-                        module_tree.tree()[use_relation.used_object().node_index()].module_name(),
-                    ) || has_parent_matching_name(
+                .into_iter()
+                .filter(|use_relation| {
+                    matches_any(
                         self.accessed(),
                         use_relation.used_object().node_index(),
                         module_tree.tree(),
-                    )) && (!self.when_same_parent()
+                    ) && (!self.when_same_parent()
                         || module_tree.tree()[use_relation.used_object().node_index()]
                             .parent_index()
                             == node.parent_index())
                 })
             {
-                return Err(RuleViolation::new(
+                violations.push(RuleViolation::new(
                     RuleViolationType::SingleLocation,
                     Box::new(self.clone()),
-                    vec![use_relation.clone()],
+                    vec![use_relation],
                 ));
             }
         }
-        Ok(())
+        violations
     }
 
     fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
-        layer_names.contains(self.accessor())
+        layer_names.contains(self.accessor().as_str())
             && self
                 .accessed()
                 .iter()
-                .all(|layer| layer_names.contains(layer))
+                .all(|layer| layer_names.contains(layer.as_str()))
+    }
+
+    fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        MayNotAccess::resolve_external_layers(self, external_layers);
     }
 }
 
 impl AccessRule for MayOnlyBeAccessedBy {
-    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
-        for node in module_tree.tree().iter().filter(|node| {
-            !self.accessors().contains(node.module_name())
-                && !has_parent_matching_name(self.accessors(), node.index(), module_tree.tree())
-        }) {
-            if let Some(use_relation) = node
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation<'_>> {
+        first_violation(self.check_all(module_tree))
+    }
+
+    fn check_all(&self, module_tree: &ModuleTree) -> Vec<RuleViolation<'_>> {
+        let mut violations = Vec::new();
+        for node in module_tree
+            .tree()
+            .iter()
+            .filter(|node| !matches_any(self.accessors(), node.index(), module_tree.tree()))
+        {
+            for use_relation in node
                 .use_relations(module_tree.tree(), module_tree.possible_uses(), false)
-                .iter()
-                .find(|use_relation| {
-                    (self.accessed()
-                        == module_tree.tree()[use_relation.used_object().node_index()]
-                            .module_name()
-                        || has_parent_matching_name(
-                            &hash_set![self.accessed().clone()],
-                            use_relation.used_object().node_index(),
-                            module_tree.tree(),
-                        ))
-                        && (!self.when_same_parent()
-                            || module_tree.tree()[use_relation.used_object().node_index()]
-                                .parent_index()
-                                == node.parent_index())
+                .into_iter()
+                .filter(|use_relation| {
+                    matches_layer(
+                        self.accessed(),
+                        use_relation.used_object().node_index(),
+                        module_tree.tree(),
+                    ) && (!self.when_same_parent()
+                        || module_tree.tree()[use_relation.used_object().node_index()]
+                            .parent_index()
+                            == node.parent_index())
                 })
             {
-                return Err(RuleViolation::new(
+                violations.push(RuleViolation::new(
                     RuleViolationType::SingleLocation,
                     Box::new(self.clone()),
-                    vec![use_relation.clone()],
+                    vec![use_relation],
                 ));
             }
         }
-        Ok(())
+        violations
     }
 
     fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
-        layer_names.contains(self.accessed())
+        layer_names.contains(self.accessed().as_str())
             && self
                 .accessors()
                 .iter()
-                .all(|layer| layer_names.contains(layer))
+                .all(|layer| layer_names.contains(layer.as_str()))
+    }
+
+    fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        MayOnlyBeAccessedBy::resolve_external_layers(self, external_layers);
     }
 }
 
 impl AccessRule for MayNotBeAccessedBy {
-    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
-        for node in module_tree.tree().iter().filter(|node| {
-            self.accessors().contains(node.module_name())
-                || has_parent_matching_name(self.accessors(), node.index(), module_tree.tree())
-        }) {
-            if let Some(use_relation) = node
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation<'_>> {
+        first_violation(self.check_all(module_tree))
+    }
+
+    fn check_all(&self, module_tree: &ModuleTree) -> Vec<RuleViolation<'_>> {
+        let mut violations = Vec::new();
+        for node in module_tree
+            .tree()
+            .iter()
+            .filter(|node| matches_any(self.accessors(), node.index(), module_tree.tree()))
+        {
+            for use_relation in node
                 .use_relations(module_tree.tree(), module_tree.possible_uses(), false)
-                .iter()
-                .find(|use_relation| {
-                    (self.accessed()
-                        == module_tree.tree()[use_relation.used_object().node_index()]
-                            .module_name()
-                        || has_parent_matching_name(
-                            &hash_set![self.accessed().clone()],
-                            use_relation.used_object().node_index(),
-                            module_tree.tree(),
-                        ))
-                        && (!self.when_same_parent()
-                            || module_tree.tree()[use_relation.used_object().node_index()]
-                                .parent_index()
-                                == node.parent_index())
+                .into_iter()
+                .filter(|use_relation| {
+                    matches_layer(
+                        self.accessed(),
+                        use_relation.used_object().node_index(),
+                        module_tree.tree(),
+                    ) && (!self.when_same_parent()
+                        || module_tree.tree()[use_relation.used_object().node_index()]
+                            .parent_index()
+                            == node.parent_index())
                 })
             {
-                return Err(RuleViolation::new(
+                violations.push(RuleViolation::new(
                     RuleViolationType::SingleLocation,
                     Box::new(self.clone()),
-                    vec![use_relation.clone()],
+                    vec![use_relation],
                 ));
             }
         }
-        Ok(())
+        violations
     }
 
     fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
-        layer_names.contains(self.accessed())
+        layer_names.contains(self.accessed().as_str())
             && self
                 .accessors()
                 .iter()
-                .all(|layer| layer_names.contains(layer))
+                .all(|layer| layer_names.contains(layer.as_str()))
+    }
+
+    fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        MayNotBeAccessedBy::resolve_external_layers(self, external_layers);
     }
 }
 
 impl AccessRule for NoParentAccess {
-    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation<'_>> {
+        first_violation(self.check_all(module_tree))
+    }
+
+    fn check_all(&self, module_tree: &ModuleTree) -> Vec<RuleViolation<'_>> {
+        let mut violations = Vec::new();
         for node in module_tree
             .tree()
             .iter()
             .filter(|node| node.parent_index().is_some())
         {
-            if let Some(use_relation) = node
+            for use_relation in node
                 .use_relations(module_tree.tree(), module_tree.possible_uses(), false)
-                .iter()
-                .find(|use_relation| {
+                .into_iter()
+                .filter(|use_relation| {
                     node.parent_index().is_some()
                         && node.parent_index().unwrap() == use_relation.used_object().node_index()
                 })
             {
-                return Err(RuleViolation::new(
+                violations.push(RuleViolation::new(
                     RuleViolationType::SingleLocation,
                     Box::new(self.clone()),
-                    vec![use_relation.clone()],
+                    vec![use_relation],
                 ));
             }
         }
-        Ok(())
+        violations
     }
 
     fn validate(&self, _layer_names: &HashSet<String, RandomState>) -> bool {
@@ -228,15 +381,28 @@ impl AccessRule for NoParentAccess {
 }
 
 impl AccessRule for NoModuleCyclicDependencies {
-    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
-        if let Some(involved) = contains_cyclic_dependency(module_tree) {
-            return Err(RuleViolation::new(
-                RuleViolationType::Cycle,
-                Box::new(self.clone()),
-                involved,
-            ));
-        }
-        Ok(())
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation<'_>> {
+        first_violation(self.check_all(module_tree))
+    }
+
+    fn check_all(&self, module_tree: &ModuleTree) -> Vec<RuleViolation<'_>> {
+        module_cycles(module_tree)
+            .into_iter()
+            .map(|cycle_group| {
+                let member_paths = member_paths(&cycle_group, module_tree.tree());
+                let description = format!(
+                    "The modules {} depend on each other",
+                    quoted_list(member_paths.iter())
+                );
+                RuleViolation::new(
+                    RuleViolationType::Cycle,
+                    Box::new(self.clone()),
+                    cycle_group.cycle().clone(),
+                )
+                .with_cycle_members(member_paths)
+                .with_description(description)
+            })
+            .collect()
     }
 
     fn validate(&self, _layer_names: &HashSet<String, RandomState>) -> bool {
@@ -245,15 +411,32 @@ impl AccessRule for NoModuleCyclicDependencies {
 }
 
 impl AccessRule for NoLayerCyclicDependencies {
-    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation> {
-        if let Some(involved) = contains_cyclic_dependency_on_any_level(module_tree) {
-            return Err(RuleViolation::new(
-                RuleViolationType::Cycle,
-                Box::new(self.clone()),
-                involved,
-            ));
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation<'_>> {
+        first_violation(self.check_all(module_tree))
+    }
+
+    fn check_all(&self, module_tree: &ModuleTree) -> Vec<RuleViolation<'_>> {
+        let mut violations = Vec::new();
+        for (level, cycle_groups) in level_cycles(module_tree) {
+            for cycle_group in cycle_groups {
+                let member_paths = member_paths(&cycle_group, module_tree.tree());
+                let description = format!(
+                    "The modules {} on level {} depend on each other",
+                    quoted_list(member_paths.iter()),
+                    level
+                );
+                violations.push(
+                    RuleViolation::new(
+                        RuleViolationType::Cycle,
+                        Box::new(self.clone()),
+                        cycle_group.cycle().clone(),
+                    )
+                    .with_cycle_members(member_paths)
+                    .with_description(description),
+                );
+            }
         }
-        Ok(())
+        violations
     }
 
     fn validate(&self, _layer_names: &HashSet<String, RandomState>) -> bool {
@@ -261,7 +444,500 @@ impl AccessRule for NoLayerCyclicDependencies {
     }
 }
 
-fn has_parent_matching_name(
+impl AccessRule for NoNamedLayerCyclicDependencies {
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation<'_>> {
+        first_violation(self.check_all(module_tree))
+    }
+
+    fn check_all(&self, module_tree: &ModuleTree) -> Vec<RuleViolation<'_>> {
+        layer_cycles(module_tree, self.layers())
+            .into_iter()
+            .map(|cycle_group| {
+                let member_layers: Vec<String> = cycle_group
+                    .members()
+                    .iter()
+                    .map(|member| self.layers()[*member].to_string())
+                    .collect();
+                let description = format!(
+                    "The layers {} depend on each other: {}",
+                    quoted_list(member_layers.iter()),
+                    cycle_group
+                        .path()
+                        .iter()
+                        .map(|layer| self.layers()[*layer].as_str())
+                        .join(" -> ")
+                );
+                RuleViolation::new(
+                    RuleViolationType::Cycle,
+                    Box::new(self.clone()),
+                    cycle_group.cycle().clone(),
+                )
+                .with_cycle_members(member_layers)
+                .with_description(description)
+            })
+            .collect()
+    }
+
+    fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
+        self.layers()
+            .iter()
+            .all(|layer| layer_names.contains(layer.as_str()))
+    }
+
+    fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        NoNamedLayerCyclicDependencies::resolve_external_layers(self, external_layers);
+    }
+}
+
+impl AccessRule for LayeredArchitecture {
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation<'_>> {
+        first_violation(self.check_all(module_tree))
+    }
+
+    fn check_all(&self, module_tree: &ModuleTree) -> Vec<RuleViolation<'_>> {
+        let tree = module_tree.tree();
+        let layer_index = |node_index: usize| {
+            self.layers()
+                .iter()
+                .position(|layer| matches_layer(layer, node_index, tree))
+        };
+
+        let mut violations = Vec::new();
+        for node in tree.iter() {
+            let accessor_layer = match layer_index(node.index()) {
+                Some(accessor_layer) => accessor_layer,
+                None => continue,
+            };
+            for use_relation in node.use_relations(tree, module_tree.possible_uses(), false) {
+                let accessed_layer = match layer_index(use_relation.used_object().node_index()) {
+                    Some(accessed_layer) => accessed_layer,
+                    None => continue,
+                };
+                let description = if accessed_layer < accessor_layer {
+                    format!(
+                        "Layer `{}` may not access the higher layer `{}`",
+                        self.layers()[accessor_layer],
+                        self.layers()[accessed_layer]
+                    )
+                } else if self.strict() && accessed_layer > accessor_layer + 1 {
+                    format!(
+                        "Layer `{}` may only access the layer directly below it (`{}`), but accesses `{}`",
+                        self.layers()[accessor_layer],
+                        self.layers()[accessor_layer + 1],
+                        self.layers()[accessed_layer]
+                    )
+                } else {
+                    continue;
+                };
+                violations.push(
+                    RuleViolation::new(
+                        RuleViolationType::SingleLocation,
+                        Box::new(self.clone()),
+                        vec![use_relation],
+                    )
+                    .with_description(description),
+                );
+            }
+        }
+        violations
+    }
+
+    fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
+        self.layers()
+            .iter()
+            .all(|layer| layer_names.contains(layer.as_str()))
+    }
+
+    fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        LayeredArchitecture::resolve_external_layers(self, external_layers);
+    }
+}
+
+impl AccessRule for OnionArchitecture {
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation<'_>> {
+        first_violation(self.check_all(module_tree))
+    }
+
+    fn check_all(&self, module_tree: &ModuleTree) -> Vec<RuleViolation<'_>> {
+        let tree = module_tree.tree();
+        let rings = self.rings();
+        let ring_of = |node_index: usize| {
+            rings.iter().enumerate().find_map(|(ring, (_, layers))| {
+                layers
+                    .iter()
+                    .find(|layer| matches_layer(layer, node_index, tree))
+                    .map(|layer| (ring, layer))
+            })
+        };
+
+        let mut violations = Vec::new();
+        for node in tree.iter() {
+            let (accessor_ring, accessor_layer) = match ring_of(node.index()) {
+                Some(accessor) => accessor,
+                None => continue,
+            };
+            for use_relation in node.use_relations(tree, module_tree.possible_uses(), false) {
+                let (accessed_ring, accessed_layer) =
+                    match ring_of(use_relation.used_object().node_index()) {
+                        Some(accessed) => accessed,
+                        None => continue,
+                    };
+                let is_other_adapter = accessor_ring == rings.len() - 1
+                    && accessed_ring == accessor_ring
+                    && accessor_layer != accessed_layer;
+                if accessed_ring <= accessor_ring && !is_other_adapter {
+                    continue;
+                }
+                violations.push(
+                    RuleViolation::new(
+                        RuleViolationType::SingleLocation,
+                        Box::new(self.clone()),
+                        vec![use_relation],
+                    )
+                    .with_description(format!(
+                        "{} `{}` may not access {} `{}`",
+                        rings[accessor_ring].0,
+                        accessor_layer,
+                        rings[accessed_ring].0.to_lowercase(),
+                        accessed_layer
+                    )),
+                );
+            }
+        }
+        violations
+    }
+
+    fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
+        self.rings()
+            .iter()
+            .flat_map(|(_, layers)| layers.iter())
+            .all(|layer| layer_names.contains(layer.as_str()))
+    }
+
+    fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        OnionArchitecture::resolve_external_layers(self, external_layers);
+    }
+}
+
+impl AccessRule for ItemMayNotAccess {
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation<'_>> {
+        first_violation(self.check_all(module_tree))
+    }
+
+    fn check_all(&self, module_tree: &ModuleTree) -> Vec<RuleViolation<'_>> {
+        let tree = module_tree.tree();
+        let mut violations = Vec::new();
+        for node in tree.iter() {
+            for use_relation in node.use_relations_per_use(tree, module_tree.possible_uses()) {
+                let used_object = use_relation.used_object();
+                let text_range = use_relation.using_object().usable_object().text_range();
+                let accessor = match node.item_scopes_containing(text_range).find(|item_scope| {
+                    !is_self_use(item_scope, node.index(), used_object)
+                        && matches_item_scope(self.accessor(), item_scope, node.index(), tree)
+                }) {
+                    Some(accessor) => accessor,
+                    None => continue,
+                };
+                if !self
+                    .accessed()
+                    .iter()
+                    .any(|selector| matches_used_object(selector, used_object, tree))
+                {
+                    continue;
+                }
+                let description = format!(
+                    "Item `{}` may not access `{}`",
+                    accessor.name(),
+                    used_object.usable_object().object_name()
+                );
+                violations.push(
+                    RuleViolation::new(
+                        RuleViolationType::SingleLocation,
+                        Box::new(self.clone()),
+                        vec![use_relation],
+                    )
+                    .with_description(description),
+                );
+            }
+        }
+        violations
+    }
+
+    fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
+        std::iter::once(self.accessor())
+            .chain(self.accessed().iter())
+            .filter_map(|selector| selector.layer())
+            .all(|layer| layer_names.contains(layer.as_str()))
+    }
+
+    fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        ItemMayNotAccess::resolve_external_layers(self, external_layers);
+    }
+}
+
+impl AccessRule for ItemMayOnlyBeAccessedBy {
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation<'_>> {
+        first_violation(self.check_all(module_tree))
+    }
+
+    fn check_all(&self, module_tree: &ModuleTree) -> Vec<RuleViolation<'_>> {
+        let tree = module_tree.tree();
+        let mut violations = Vec::new();
+        for node in tree.iter() {
+            for use_relation in node.use_relations_per_use(tree, module_tree.possible_uses()) {
+                let used_object = use_relation.used_object();
+                if !matches_used_object(self.accessed(), used_object, tree) {
+                    continue;
+                }
+                let text_range = use_relation.using_object().usable_object().text_range();
+                let item_scopes: Vec<&ItemScope> = node
+                    .item_scopes_containing(text_range)
+                    .filter(|item_scope| !is_self_use(item_scope, node.index(), used_object))
+                    .collect();
+                let accessor = match item_scopes.first() {
+                    Some(accessor) => accessor,
+                    None => continue,
+                };
+                if item_scopes.iter().any(|item_scope| {
+                    self.accessors().iter().any(|selector| {
+                        matches_item_scope(selector, item_scope, node.index(), tree)
+                    })
+                }) {
+                    continue;
+                }
+                let description = format!(
+                    "`{}` may only be accessed by `{}`, but is accessed by `{}`",
+                    used_object.usable_object().object_name(),
+                    self.accessors().iter().join("`, `"),
+                    accessor.name()
+                );
+                violations.push(
+                    RuleViolation::new(
+                        RuleViolationType::SingleLocation,
+                        Box::new(self.clone()),
+                        vec![use_relation],
+                    )
+                    .with_description(description),
+                );
+            }
+        }
+        violations
+    }
+
+    fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
+        std::iter::once(self.accessed())
+            .chain(self.accessors().iter())
+            .filter_map(|selector| selector.layer())
+            .all(|layer| layer_names.contains(layer.as_str()))
+    }
+
+    fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        ItemMayOnlyBeAccessedBy::resolve_external_layers(self, external_layers);
+    }
+}
+
+impl AccessRule for NamingConvention {
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation<'_>> {
+        first_violation(self.check_all(module_tree))
+    }
+
+    fn check_all(&self, module_tree: &ModuleTree) -> Vec<RuleViolation<'_>> {
+        let tree = module_tree.tree();
+        let mut violations = Vec::new();
+        for node in tree
+            .iter()
+            .filter(|node| !node.is_external() && matches_layer(self.layer(), node.index(), tree))
+        {
+            for usable_object in node.usable_objects().iter().filter(|usable_object| {
+                usable_object.object_type() == self.object_type()
+                    && matches_segment(self.pattern(), usable_object.object_name())
+                        != self.must_match()
+            }) {
+                let description = format!(
+                    "{:?} `{}` in `{}` {} `{}`",
+                    self.object_type(),
+                    usable_object.object_name(),
+                    self.layer(),
+                    if self.must_match() {
+                        "must match"
+                    } else {
+                        "must not match"
+                    },
+                    self.pattern()
+                );
+                violations.push(
+                    RuleViolation::new(
+                        RuleViolationType::SingleObject,
+                        Box::new(self.clone()),
+                        vec![],
+                    )
+                    .with_locations(vec![object_location(node, usable_object, tree)])
+                    .with_description(description),
+                );
+            }
+        }
+        violations
+    }
+
+    fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
+        layer_names.contains(self.layer().as_str())
+    }
+
+    fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        NamingConvention::resolve_external_layers(self, external_layers);
+    }
+}
+
+impl AccessRule for VisibilityRule {
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation<'_>> {
+        first_violation(self.check_all(module_tree))
+    }
+
+    fn check_all(&self, module_tree: &ModuleTree) -> Vec<RuleViolation<'_>> {
+        let tree = module_tree.tree();
+        let mut violations = Vec::new();
+        for node in tree
+            .iter()
+            .filter(|node| !node.is_external() && matches_layer(self.layer(), node.index(), tree))
+        {
+            for usable_object in node.usable_objects().iter().filter(|usable_object| {
+                usable_object.object_type() == self.object_type()
+                    && !self.allows(usable_object.visibility())
+            }) {
+                let description = format!(
+                    "{:?} `{}` in `{}` is {}, but must be at {} {}",
+                    self.object_type(),
+                    usable_object.object_name(),
+                    self.layer(),
+                    usable_object.visibility(),
+                    if self.is_at_most() { "most" } else { "least" },
+                    self.visibility()
+                );
+                violations.push(
+                    RuleViolation::new(
+                        RuleViolationType::SingleObject,
+                        Box::new(self.clone()),
+                        vec![],
+                    )
+                    .with_locations(vec![object_location(node, usable_object, tree)])
+                    .with_description(description),
+                );
+            }
+        }
+        violations
+    }
+
+    fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
+        layer_names.contains(self.layer().as_str())
+    }
+
+    fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        VisibilityRule::resolve_external_layers(self, external_layers);
+    }
+}
+
+impl AccessRule for ForbiddenPaths {
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation<'_>> {
+        first_violation(self.check_all(module_tree))
+    }
+
+    fn check_all(&self, module_tree: &ModuleTree) -> Vec<RuleViolation<'_>> {
+        let tree = module_tree.tree();
+        let mut violations = Vec::new();
+        for node in tree
+            .iter()
+            .filter(|node| !node.is_external() && matches_layer(self.layer(), node.index(), tree))
+        {
+            for usable_object in node.usable_objects().iter().filter(|usable_object| {
+                usable_object.object_type() == ObjectType::Use
+                    || usable_object.object_type() == ObjectType::RePublish
+                    || usable_object.object_type() == ObjectType::ImplicitUse
+            }) {
+                let pattern = match self.find_matching_pattern(usable_object.object_name()) {
+                    Some(pattern) => pattern,
+                    None => continue,
+                };
+                violations.push(
+                    RuleViolation::new(
+                        RuleViolationType::SingleObject,
+                        Box::new(self.clone()),
+                        vec![],
+                    )
+                    .with_locations(vec![object_location(node, usable_object, tree)])
+                    .with_description(format!(
+                        "`{}` may not be used in `{}`, because it matches the forbidden path `{}`",
+                        usable_object.object_name(),
+                        self.layer(),
+                        pattern
+                    )),
+                );
+            }
+        }
+        violations
+    }
+
+    fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
+        layer_names.contains(self.layer().as_str())
+    }
+
+    fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        ForbiddenPaths::resolve_external_layers(self, external_layers);
+    }
+}
+
+impl AccessRule for UnsafeOnlyIn {
+    fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation<'_>> {
+        first_violation(self.check_all(module_tree))
+    }
+
+    fn check_all(&self, module_tree: &ModuleTree) -> Vec<RuleViolation<'_>> {
+        let tree = module_tree.tree();
+        let mut violations = Vec::new();
+        for node in tree
+            .iter()
+            .filter(|node| !matches_any(self.layers(), node.index(), tree))
+        {
+            for unsafe_site in node.unsafe_sites() {
+                violations.push(
+                    RuleViolation::new(
+                        RuleViolationType::SingleObject,
+                        Box::new(self.clone()),
+                        vec![],
+                    )
+                    .with_locations(vec![ViolationLocation::new(
+                        node.index(),
+                        node.get_fully_qualified_path(tree),
+                        unsafe_site.kind().to_string(),
+                        *unsafe_site.text_range(),
+                    )])
+                    .with_description(format!(
+                        "{} in `{}` is not within the layers `{}`",
+                        unsafe_site.kind(),
+                        node.get_fully_qualified_path(tree),
+                        self.layers()
+                            .iter()
+                            .sorted_by_key(|layer| layer.as_str())
+                            .join("`, `")
+                    )),
+                );
+            }
+        }
+        violations
+    }
+
+    fn validate(&self, layer_names: &HashSet<String, RandomState>) -> bool {
+        self.layers()
+            .iter()
+            .all(|layer| layer_names.contains(layer.as_str()))
+    }
+
+    fn resolve_external_layers(&mut self, external_layers: &HashMap<String, HashSet<String>>) {
+        UnsafeOnlyIn::resolve_external_layers(self, external_layers);
+    }
+}
+
+/// Returns `true` if any ancestor of the node at `node_index` has a module name contained in `accessor_name`.
+pub fn has_parent_matching_name(
     accessor_name: &HashSet<String>,
     mut node_index: usize,
     tree: &[ModuleNode],
@@ -274,3 +950,101 @@ fn has_parent_matching_name(
     }
     false
 }
+
+/// Returns `true` if the `selector` matches the node at `node_index` or any of its parents.
+/// An external crate is matched by its name or by the name of the external layer it belongs to.
+pub fn matches_layer(selector: &LayerSelector, node_index: usize, tree: &[ModuleNode]) -> bool {
+    let mut current_index = Some(node_index);
+    while let Some(index) = current_index {
+        if selector.matches_path(&tree[index].get_fully_qualified_path(tree))
+            || (tree[index].is_external()
+                && selector.matches_external_crate(tree[index].module_name()))
+        {
+            return true;
+        }
+        current_index = tree[index].parent_index();
+    }
+    false
+}
+
+/// Returns `true` if any of the `selectors` matches the node at `node_index` or any of its parents.
+pub fn matches_any(
+    selectors: &HashSet<LayerSelector>,
+    node_index: usize,
+    tree: &[ModuleNode],
+) -> bool {
+    selectors
+        .iter()
+        .any(|selector| matches_layer(selector, node_index, tree))
+}
+
+/// Returns `true` if the `selector` matches the item of the `item_scope`, which is declared in the node at `node_index`.
+pub fn matches_item_scope(
+    selector: &ItemSelector,
+    item_scope: &ItemScope,
+    node_index: usize,
+    tree: &[ModuleNode],
+) -> bool {
+    selector.matches_item(item_scope.name(), item_scope.object_type())
+        && selector
+            .layer()
+            .is_none_or(|layer| matches_layer(layer, node_index, tree))
+}
+
+/// Returns `true` if the `selector` matches the used object, e.g. the accessed struct or external object.
+pub fn matches_used_object(
+    selector: &ItemSelector,
+    used_object: &ObjectUse,
+    tree: &[ModuleNode],
+) -> bool {
+    let usable_object = used_object.usable_object();
+    selector.matches_item(
+        usable_object.object_name(),
+        Some(usable_object.object_type()),
+    ) && selector
+        .layer()
+        .is_none_or(|layer| matches_layer(layer, used_object.node_index(), tree))
+}
+
+/// An item may always use itself, e.g. within its `impl` blocks.
+fn is_self_use(item_scope: &ItemScope, node_index: usize, used_object: &ObjectUse) -> bool {
+    used_object.node_index() == node_index
+        && used_object.usable_object().object_name() == item_scope.name()
+}
+
+/// Returns the fully qualified paths of the modules of the `cycle_group`
+fn member_paths(cycle_group: &CycleGroup, tree: &[ModuleNode]) -> Vec<String> {
+    cycle_group
+        .members()
+        .iter()
+        .map(|member| tree[*member].get_fully_qualified_path(tree))
+        .collect()
+}
+
+/// Locates the `usable_object` that is declared or used in the `node`, e.g. for a `SingleObject` violation.
+fn object_location(
+    node: &ModuleNode,
+    usable_object: &UsableObject,
+    tree: &[ModuleNode],
+) -> ViolationLocation {
+    ViolationLocation::new(
+        node.index(),
+        node.get_fully_qualified_path(tree),
+        usable_object.object_name().clone(),
+        *usable_object.text_range(),
+    )
+}
+
+/// External crates are only restricted by "may only" rules, if they are part of an external layer.
+fn is_undeclared_external(
+    node_index: usize,
+    tree: &[ModuleNode],
+    declared_external_crates: &HashSet<String>,
+) -> bool {
+    tree[node_index].is_external()
+        && !declared_external_crates.contains(tree[node_index].module_name())
+}
+
+fn first_violation(violations: Vec<RuleViolation>) -> Result<(), RuleViolation> {
+    violations.into_iter().next().map_or(Ok(()), Err)
+}
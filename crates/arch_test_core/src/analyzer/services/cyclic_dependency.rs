@@ -1,205 +1,235 @@
-use std::collections::HashMap;
-
-use itertools::Itertools;
+use std::collections::{HashMap, HashSet, VecDeque};
 
+use crate::analyzer::domain_values::{CycleGroup, LayerSelector};
+use crate::analyzer::services::matches_layer;
 use crate::parser::domain_values::UseRelation;
 use crate::parser::materials::ModuleTree;
 
-pub fn contains_cyclic_dependency(module_tree: &ModuleTree) -> Option<Vec<UseRelation>> {
-    let mut visited_nodes: Vec<UseRelation> = Vec::new();
-    for (index, node) in module_tree.tree().iter().enumerate() {
-        if node
-            .use_relations(module_tree.tree(), module_tree.possible_uses(), false)
-            .iter()
-            .filter(|use_relation| use_relation.used_object().node_index() != index)
-            .dedup_by(|left, right| {
-                left.used_object().node_index() == right.used_object().node_index()
-            })
-            .any(|use_relation| {
-                visited_nodes.clear();
-                visited_nodes.push(use_relation.clone());
-                find_traverse(
-                    &mut visited_nodes,
-                    use_relation.used_object().node_index(),
-                    module_tree,
-                )
-            })
-        {
-            let last_index = visited_nodes.last().cloned().unwrap();
-            let mut result = vec![last_index.clone()];
-            for node in visited_nodes.into_iter().rev().skip(1) {
-                let using_index = node.using_object().node_index();
-                result.push(node);
-                if using_index == last_index.used_object().node_index() {
-                    break;
-                }
-            }
-            result.reverse();
-            return Some(result);
-        }
-    }
-    None
-}
+/// The edges of a dependency graph, i.e. for each graph node the targets and a `UseRelation` that causes the edge
+pub type DependencyGraph = HashMap<usize, Vec<(usize, UseRelation)>>;
 
-fn find_traverse(
-    visited_nodes: &mut Vec<UseRelation>,
-    current_index: usize,
-    module_tree: &ModuleTree,
-) -> bool {
-    if visited_nodes
+/// Returns every group of modules that depend on each other, ordered by their first member.
+pub fn module_cycles(module_tree: &ModuleTree) -> Vec<CycleGroup> {
+    let tree = module_tree.tree();
+    let graph: DependencyGraph = tree
         .iter()
-        .any(|use_relation| use_relation.using_object().node_index() == current_index)
-    {
-        return true;
-    }
-    for use_relation in module_tree.tree()[current_index]
-        .use_relations(module_tree.tree(), module_tree.possible_uses(), false)
-        .iter()
-        .filter(|use_relation| use_relation.used_object().node_index() != current_index)
-        .dedup_by(|left, right| left.used_object().node_index() == right.used_object().node_index())
-    {
-        visited_nodes.push(use_relation.clone());
-        if find_traverse(
-            visited_nodes,
-            use_relation.used_object().node_index(),
-            module_tree,
-        ) {
-            return true;
-        }
-        visited_nodes.pop();
-    }
-    false
+        .map(|node| {
+            let edges = node
+                .use_relations(tree, module_tree.possible_uses(), false)
+                .into_iter()
+                .filter(|use_relation| use_relation.used_object().node_index() != node.index())
+                .map(|use_relation| (use_relation.used_object().node_index(), use_relation))
+                .collect();
+            (node.index(), edges)
+        })
+        .collect();
+    cycle_groups(graph)
 }
 
-pub fn contains_cyclic_dependency_on_any_level(
-    module_tree: &ModuleTree,
-) -> Option<Vec<UseRelation>> {
+/// Returns every group of modules on the same tree level that depend on each other, level by level.
+/// The uses of a module's children are considered to be uses of the module.
+pub fn level_cycles(module_tree: &ModuleTree) -> Vec<(usize, Vec<CycleGroup>)> {
+    let mut result = Vec::new();
     let mut current_level = 1;
     while module_tree
         .tree()
         .iter()
         .any(|node| node.level() == current_level)
     {
-        if let Some(involved) = contains_cyclic_dependency_on_level(module_tree, current_level) {
-            return Some(involved);
+        let cycle_groups = cycle_groups(level_graph(module_tree, current_level));
+        if !cycle_groups.is_empty() {
+            result.push((current_level, cycle_groups));
         }
         current_level += 1;
     }
-    None
+    result
 }
 
-pub fn contains_cyclic_dependency_on_level(
-    module_tree: &ModuleTree,
-    level: usize,
-) -> Option<Vec<UseRelation>> {
+fn level_graph(module_tree: &ModuleTree, level: usize) -> DependencyGraph {
+    let tree = module_tree.tree();
     let mut node_mapping = HashMap::new();
-    let mut use_relations_per_level = HashMap::new();
-    let current_tree = module_tree.tree();
+    for node in tree.iter().filter(|node| node.level() == level) {
+        node_mapping.insert(node.index(), node.index());
+        for node_index in node.included_nodes(tree) {
+            node_mapping.insert(node_index, node.index());
+        }
+    }
 
-    current_tree
-        .iter()
-        .enumerate()
-        .filter(|(_, node)| node.level() == level)
-        .for_each(|(index, node)| {
-            let included_nodes: Vec<usize> = node.included_nodes(current_tree);
-            node_mapping.insert(index, index);
-            for node_index in included_nodes.iter() {
-                node_mapping.insert(*node_index, index);
-            }
-            let level_uses: Vec<UseRelation> = node
-                .use_relations(current_tree, module_tree.possible_uses(), true)
+    tree.iter()
+        .filter(|node| node.level() == level)
+        .map(|node| {
+            let edges = node
+                .use_relations(tree, module_tree.possible_uses(), true)
                 .into_iter()
-                .filter(|use_relation| {
-                    !included_nodes.contains(&use_relation.used_object().node_index())
-                        && use_relation.used_object().node_index() != index
+                .filter_map(|use_relation| {
+                    node_mapping
+                        .get(&use_relation.used_object().node_index())
+                        .filter(|target| **target != node.index())
+                        .map(|target| (*target, use_relation))
                 })
                 .collect();
-            use_relations_per_level.insert(index, level_uses);
-        });
+            (node.index(), edges)
+        })
+        .collect()
+}
 
-    let mut visited_nodes: Vec<UseRelation> = Vec::new();
-    for (_index, use_relations) in use_relations_per_level.iter() {
-        if use_relations
-            .iter()
-            .filter(|use_relation| {
-                node_mapping.contains_key(&use_relation.used_object().node_index())
-            })
-            .dedup_by(|left, right| {
-                node_mapping.get(&left.used_object().node_index()).unwrap()
-                    == node_mapping.get(&right.used_object().node_index()).unwrap()
-            })
-            .any(|use_relation| {
-                visited_nodes.clear();
-                visited_nodes.push(use_relation.clone());
-                find_traverse_on_level(
-                    &mut visited_nodes,
-                    *node_mapping
-                        .get(&use_relation.used_object().node_index())
-                        .unwrap(),
-                    &node_mapping,
-                    &use_relations_per_level,
-                )
-            })
-        {
-            let last_index = visited_nodes.last().cloned().unwrap();
-            let mut result = vec![last_index.clone()];
-            for node in visited_nodes.into_iter().rev().skip(1) {
-                let using_index = node_mapping
-                    .get(&node.using_object().node_index())
-                    .cloned()
-                    .unwrap();
-                result.push(node);
-                if using_index
-                    == *node_mapping
-                        .get(&last_index.used_object().node_index())
-                        .unwrap()
+/// Returns every group of the `layers` that depend on each other. The members of the groups are indices into `layers`.
+pub fn layer_cycles(module_tree: &ModuleTree, layers: &[LayerSelector]) -> Vec<CycleGroup> {
+    let tree = module_tree.tree();
+    let layers_of = |node_index: usize| -> Vec<usize> {
+        (0..layers.len())
+            .filter(|layer| matches_layer(&layers[*layer], node_index, tree))
+            .collect()
+    };
+
+    let mut graph: DependencyGraph = (0..layers.len()).map(|layer| (layer, Vec::new())).collect();
+    for node in tree.iter() {
+        let accessor_layers = layers_of(node.index());
+        if accessor_layers.is_empty() {
+            continue;
+        }
+        for use_relation in node.use_relations(tree, module_tree.possible_uses(), false) {
+            let accessed_layers = layers_of(use_relation.used_object().node_index());
+            for accessor_layer in accessor_layers
+                .iter()
+                .filter(|layer| !accessed_layers.contains(layer))
+            {
+                for accessed_layer in accessed_layers
+                    .iter()
+                    .filter(|layer| !accessor_layers.contains(layer))
                 {
-                    break;
+                    let edges = graph.get_mut(accessor_layer).unwrap();
+                    if !edges.iter().any(|(target, _)| target == accessed_layer) {
+                        edges.push((*accessed_layer, use_relation.clone()));
+                    }
                 }
             }
-            result.reverse();
-            return Some(result);
         }
     }
-    None
+    cycle_groups(graph)
 }
 
-fn find_traverse_on_level(
-    visited_nodes: &mut Vec<UseRelation>,
-    current_index: usize,
-    node_mapping: &HashMap<usize, usize>,
-    use_relations_per_level: &HashMap<usize, Vec<UseRelation>>,
-) -> bool {
-    if visited_nodes.iter().any(|use_relation| {
-        *node_mapping
-            .get(&use_relation.using_object().node_index())
-            .unwrap()
-            == current_index
-    }) {
-        return true;
+/// Finds the strongly connected components of the `graph` with Tarjan's algorithm
+/// and returns the ones with more than one member, including a shortest cycle through them.
+pub fn cycle_groups(mut graph: DependencyGraph) -> Vec<CycleGroup> {
+    for edges in graph.values_mut() {
+        edges.sort_by_key(|(target, use_relation)| {
+            (
+                *target,
+                use_relation.using_object().node_index(),
+                use_relation
+                    .using_object()
+                    .usable_object()
+                    .text_range()
+                    .start(),
+            )
+        });
     }
-    for use_relation in use_relations_per_level
-        .get(&current_index)
-        .unwrap()
-        .iter()
-        .dedup_by(|left, right| {
-            node_mapping.get(&left.used_object().node_index()).unwrap()
-                == node_mapping.get(&right.used_object().node_index()).unwrap()
+
+    let mut tarjan = Tarjan::default();
+    let mut graph_nodes: Vec<usize> = graph.keys().cloned().collect();
+    graph_nodes.sort_unstable();
+    for graph_node in graph_nodes {
+        if !tarjan.indices.contains_key(&graph_node) {
+            tarjan.visit(graph_node, &graph);
+        }
+    }
+
+    let mut result: Vec<CycleGroup> = tarjan
+        .components
+        .into_iter()
+        .filter(|component| component.len() > 1)
+        .map(|mut members| {
+            members.sort_unstable();
+            let cycle = shortest_cycle(members[0], &members, &graph);
+            let path = std::iter::once(members[0])
+                .chain(cycle.iter().map(|(target, _)| *target))
+                .collect();
+            let cycle = cycle
+                .into_iter()
+                .map(|(_, use_relation)| use_relation)
+                .collect();
+            CycleGroup::new(members, path, cycle)
         })
-    {
-        let use_relation_index = *node_mapping
-            .get(&use_relation.used_object().node_index())
-            .unwrap();
-        visited_nodes.push(use_relation.clone());
-        if find_traverse_on_level(
-            visited_nodes,
-            use_relation_index,
-            node_mapping,
-            use_relations_per_level,
-        ) {
-            return true;
+        .collect();
+    result.sort_by_key(|cycle_group| cycle_group.members()[0]);
+    result
+}
+
+#[derive(Default)]
+struct Tarjan {
+    next_index: usize,
+    indices: HashMap<usize, usize>,
+    low_links: HashMap<usize, usize>,
+    stack: Vec<usize>,
+    on_stack: HashSet<usize>,
+    components: Vec<Vec<usize>>,
+}
+
+impl Tarjan {
+    fn visit(&mut self, graph_node: usize, graph: &DependencyGraph) {
+        self.indices.insert(graph_node, self.next_index);
+        self.low_links.insert(graph_node, self.next_index);
+        self.next_index += 1;
+        self.stack.push(graph_node);
+        self.on_stack.insert(graph_node);
+
+        for (target, _) in graph.get(&graph_node).into_iter().flatten() {
+            if !graph.contains_key(target) {
+                continue;
+            }
+            if !self.indices.contains_key(target) {
+                self.visit(*target, graph);
+                let low_link = self.low_links[&graph_node].min(self.low_links[target]);
+                self.low_links.insert(graph_node, low_link);
+            } else if self.on_stack.contains(target) {
+                let low_link = self.low_links[&graph_node].min(self.indices[target]);
+                self.low_links.insert(graph_node, low_link);
+            }
+        }
+
+        if self.low_links[&graph_node] == self.indices[&graph_node] {
+            let mut component = Vec::new();
+            while let Some(member) = self.stack.pop() {
+                self.on_stack.remove(&member);
+                component.push(member);
+                if member == graph_node {
+                    break;
+                }
+            }
+            self.components.push(component);
+        }
+    }
+}
+
+/// Returns a shortest cycle from `start` back to `start` that only passes the `members` using a breadth first search.
+/// Each `UseRelation` of the cycle is returned together with the graph node it leads to.
+fn shortest_cycle(
+    start: usize,
+    members: &[usize],
+    graph: &DependencyGraph,
+) -> Vec<(usize, UseRelation)> {
+    let mut predecessors: HashMap<usize, (usize, &UseRelation)> = HashMap::new();
+    let mut queue = VecDeque::new();
+    queue.push_back(start);
+    while let Some(current) = queue.pop_front() {
+        for (target, use_relation) in graph.get(&current).into_iter().flatten() {
+            if *target == start {
+                let mut cycle = vec![(start, use_relation.clone())];
+                let mut position = current;
+                while position != start {
+                    let (predecessor, use_relation) = predecessors[&position];
+                    cycle.push((position, use_relation.clone()));
+                    position = predecessor;
+                }
+                cycle.reverse();
+                return cycle;
+            }
+            if members.contains(target) && !predecessors.contains_key(target) {
+                predecessors.insert(*target, (current, use_relation));
+                queue.push_back(*target);
+            }
         }
-        visited_nodes.pop();
     }
-    false
+    Vec::new()
 }
@@ -1,4 +1,9 @@
-pub use self::access_rule::AccessRule;
+pub use self::access_rule::{
+    has_parent_matching_name, matches_any, matches_item_scope, matches_layer, matches_used_object,
+    AccessRule,
+};
+pub use self::suppression::{suppressing, unused_suppression};
 
 mod access_rule;
 mod cyclic_dependency;
+mod suppression;
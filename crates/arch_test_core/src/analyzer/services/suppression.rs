@@ -0,0 +1,80 @@
+use crate::analyzer::domain_values::{
+    ParameterValue, RuleDescriptor, RuleViolationType, ViolatedRule, ViolationLocation,
+};
+use crate::analyzer::entities::RuleViolation;
+use crate::parser::domain_values::Suppression;
+use crate::parser::entities::ModuleNode;
+
+/// Returns the suppressions that silence the `violation` of the rule named `rule_name` as pairs of node index and suppression index.
+/// A violation is silenced, if any of its involved uses or locations is within the scope of a suppression of the rule.
+pub fn suppressing(
+    violation: &RuleViolation<'_>,
+    rule_name: &str,
+    tree: &[ModuleNode],
+) -> Vec<(usize, usize)> {
+    let mut result = Vec::new();
+    let sites = violation
+        .involved_object_uses()
+        .iter()
+        .map(|use_relation| {
+            let using_object = use_relation.using_object();
+            (
+                using_object.node_index(),
+                using_object.usable_object().text_range(),
+            )
+        })
+        .chain(
+            violation
+                .locations()
+                .iter()
+                .map(|location| (location.node_index(), location.text_range())),
+        );
+    for (node_index, text_range) in sites {
+        let node = &tree[node_index];
+        for (suppression_index, suppression) in node.suppressions().iter().enumerate() {
+            if suppression.suppresses(rule_name, text_range)
+                && !result.contains(&(node.index(), suppression_index))
+            {
+                result.push((node.index(), suppression_index));
+            }
+        }
+    }
+    result
+}
+
+/// Returns the violation that reports the suppression at `suppression_index` of the node at `node_index` as unused.
+pub fn unused_suppression(
+    node_index: usize,
+    suppression_index: usize,
+    tree: &[ModuleNode],
+) -> RuleViolation<'static> {
+    let node = &tree[node_index];
+    let suppression = &node.suppressions()[suppression_index];
+    RuleViolation::new(
+        RuleViolationType::UnusedSuppression,
+        Box::new(suppression.clone()),
+        vec![],
+    )
+    .with_locations(vec![ViolationLocation::new(
+        node_index,
+        node.get_fully_qualified_path(tree),
+        suppression.rule_name().clone(),
+        *suppression.text_range(),
+    )])
+    .with_description(format!(
+        "The suppression of `{}` does not suppress any violation",
+        suppression.rule_name()
+    ))
+}
+
+/// A suppression is violated, if it does not suppress any violation
+impl ViolatedRule for Suppression {
+    fn name(&self) -> &'static str {
+        "Suppression"
+    }
+
+    fn descriptor(&self) -> RuleDescriptor {
+        RuleDescriptor::new(self.name())
+            .with_parameter("rule", ParameterValue::text(self.rule_name()))
+    }
+}
@@ -0,0 +1,6 @@
+use crate::domain::Order;
+use sqlx::Pool;
+
+fn store(pool: Pool, order: Order) {
+    tokio::spawn(order);
+}
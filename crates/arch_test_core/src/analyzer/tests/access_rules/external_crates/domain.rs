@@ -0,0 +1,7 @@
+use diesel::PgConnection;
+
+pub struct Order;
+
+fn load(connection: PgConnection) -> Order {
+    Order
+}
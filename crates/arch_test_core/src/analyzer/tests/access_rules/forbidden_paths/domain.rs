@@ -0,0 +1,11 @@
+use std::fs;
+use std::time::Instant;
+
+pub fn run() {
+    println!("hello");
+    let start = Instant::now();
+    let content = fs::read_to_string("a.txt");
+    dbg!(start);
+    std::process::exit(1);
+    panic!("x");
+}
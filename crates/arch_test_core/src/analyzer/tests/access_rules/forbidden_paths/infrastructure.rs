@@ -0,0 +1,6 @@
+use std::fs;
+
+pub fn load() -> String {
+    println!("loading");
+    fs::read_to_string("a.txt")
+}
@@ -0,0 +1,16 @@
+use crate::infrastructure::PaymentGateway;
+
+pub struct Order {
+    id: u32,
+}
+
+impl Order {
+    pub fn pay(&self, gateway: PaymentGateway) -> Order {
+        Order::new()
+    }
+}
+
+pub struct Invoice {
+    order: Order,
+    gateway: PaymentGateway,
+}
@@ -0,0 +1,11 @@
+use sqlx::PgPool;
+
+pub struct PaymentGateway;
+
+pub struct OrderRepository {
+    pool: PgPool,
+}
+
+pub fn connect(url: String) -> PgPool {
+    PgPool::connect(url)
+}
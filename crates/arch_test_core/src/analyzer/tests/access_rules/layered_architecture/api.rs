@@ -0,0 +1,6 @@
+use crate::repository::Repository;
+use crate::services::Service;
+
+pub struct Api;
+
+fn handle(service: Service, repository: Repository) {}
@@ -0,0 +1,3 @@
+mod api;
+mod repository;
+mod services;
@@ -0,0 +1,5 @@
+use crate::repository::Repository;
+
+pub struct Service;
+
+fn serve(repository: Repository) {}
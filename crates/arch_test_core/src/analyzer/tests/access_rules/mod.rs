@@ -1,9 +1,13 @@
 use velcro::hash_set;
 
 use crate::analyzer::domain_values::access_rules::{
-    MayNotAccess, MayNotBeAccessedBy, MayOnlyAccess, MayOnlyBeAccessedBy,
-    NoLayerCyclicDependencies, NoModuleCyclicDependencies, NoParentAccess,
+    ForbiddenPaths, ItemMayNotAccess, ItemMayOnlyBeAccessedBy, LayeredArchitecture, MayNotAccess,
+    MayNotBeAccessedBy, MayOnlyAccess, MayOnlyBeAccessedBy, NamingConvention,
+    NoLayerCyclicDependencies, NoModuleCyclicDependencies, NoNamedLayerCyclicDependencies,
+    NoParentAccess, OnionArchitecture, UnsafeOnlyIn, VisibilityRule,
 };
+use crate::analyzer::domain_values::{modules, ItemSelector, RuleViolationType, Severity};
+use crate::parser::domain_values::{ObjectType, Visibility};
 use crate::{Architecture, ModuleTree};
 
 #[test]
@@ -18,6 +22,14 @@ fn no_parent_access() {
         .print(module_tree.tree());
 }
 
+#[test]
+fn no_parent_access_super() {
+    let architecture = Architecture::new(hash_set![]).with_access_rule(NoParentAccess);
+    let module_tree =
+        ModuleTree::new("src/analyzer/tests/access_rules/no_parent_access_super/main.rs");
+    assert!(architecture.check_access_rules(&module_tree).is_err());
+}
+
 #[test]
 fn no_module_cyclic_dependencies() {
     let architecture = Architecture::new(hash_set![]).with_access_rule(NoModuleCyclicDependencies);
@@ -72,6 +84,91 @@ fn cyclic_dependency_over_several_layers() {
         .print(module_tree.tree());
 }
 
+#[test]
+fn several_module_cycles() {
+    let architecture = Architecture::new(hash_set![]).with_access_rule(NoModuleCyclicDependencies);
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/several_cycles/main.rs");
+    let report = architecture
+        .check_all_access_rules(&module_tree)
+        .err()
+        .unwrap();
+    let descriptions: Vec<&str> = report
+        .violations()
+        .map(|violation| violation.description().unwrap().as_str())
+        .collect();
+    assert_eq!(
+        descriptions,
+        vec![
+            "The modules `crate::a`, `crate::b` depend on each other",
+            "The modules `crate::c`, `crate::d`, `crate::e` depend on each other"
+        ]
+    );
+    let cycle_lengths: Vec<usize> = report
+        .violations()
+        .map(|violation| violation.involved_object_uses().len())
+        .collect();
+    assert_eq!(cycle_lengths, vec![2, 3]);
+    report.print(module_tree.tree());
+}
+
+#[test]
+fn several_layer_cycles() {
+    let architecture = Architecture::new(hash_set![]).with_access_rule(NoLayerCyclicDependencies);
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/several_cycles/main.rs");
+    let report = architecture
+        .check_all_access_rules(&module_tree)
+        .err()
+        .unwrap();
+    assert_eq!(report.len(), 2);
+    assert!(report.violations().all(|violation| violation
+        .description()
+        .unwrap()
+        .ends_with("on level 1 depend on each other")));
+}
+
+#[test]
+fn named_layer_cycles() {
+    let architecture = Architecture::new(hash_set!["services".to_owned(), "materials".to_owned()])
+        .with_access_rule(NoNamedLayerCyclicDependencies::new(hash_set![
+            "services".to_owned(),
+            "materials".to_owned()
+        ]));
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/named_layer_cycles/main.rs");
+    assert!(architecture.validate_access_rules().is_ok());
+    let report = architecture
+        .check_all_access_rules(&module_tree)
+        .err()
+        .unwrap();
+    assert_eq!(report.len(), 1);
+    let violation = report.violations().next().unwrap();
+    assert_eq!(
+        violation.description().unwrap(),
+        "The layers `materials`, `services` depend on each other: materials -> services -> materials"
+    );
+    assert_eq!(violation.involved_object_uses().len(), 2);
+}
+
+#[test]
+fn named_layer_cycles_nested_layers() {
+    let architecture =
+        Architecture::new(hash_set!["domain".to_owned(), "model".to_owned()]).with_access_rule(
+            NoNamedLayerCyclicDependencies::new(hash_set!["domain".to_owned(), "model".to_owned()]),
+        );
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/named_layer_cycles/main.rs");
+    assert!(architecture.check_all_access_rules(&module_tree).is_ok());
+}
+
+#[test]
+fn named_layer_cycles_unknown_layer() {
+    let architecture = Architecture::new(hash_set!["services".to_owned()]).with_access_rule(
+        NoNamedLayerCyclicDependencies::new(hash_set![
+            "services".to_owned(),
+            "materials".to_owned()
+        ]),
+    );
+    assert!(architecture.validate_access_rules().is_err());
+}
+
 #[test]
 fn may_only_access_positive() {
     let architecture =
@@ -256,6 +353,887 @@ fn may_not_be_accessed_by_when_same_parent_negative() {
         .print(module_tree.tree());
 }
 
+#[test]
+fn may_not_access_path_pattern() {
+    let architecture = Architecture::new(hash_set![
+        "crate::layer_1::*".to_owned(),
+        "crate::**::file_2".to_owned()
+    ])
+    .with_access_rule(MayNotAccess::new(
+        "crate::layer_1::*".to_owned(),
+        hash_set!["crate::**::file_2".to_owned()],
+        false,
+    ));
+    let module_tree =
+        ModuleTree::new("src/analyzer/tests/access_rules/may_access_same_parent/main.rs");
+    assert!(architecture.validate_access_rules().is_ok());
+    assert!(architecture.check_access_rules(&module_tree).is_err());
+    architecture
+        .check_access_rules(&module_tree)
+        .err()
+        .unwrap()
+        .print(module_tree.tree());
+}
+
+#[test]
+fn may_not_access_path_pattern_positive() {
+    let architecture = Architecture::new(hash_set![
+        "crate::layer_2::*".to_owned(),
+        "regex:file_1$".to_owned()
+    ])
+    .with_access_rule(MayNotAccess::new(
+        "crate::layer_2::*".to_owned(),
+        hash_set!["regex:file_1$".to_owned()],
+        false,
+    ));
+    let module_tree =
+        ModuleTree::new("src/analyzer/tests/access_rules/may_access_same_parent/main.rs");
+    assert!(architecture.validate_access_rules().is_ok());
+    assert!(architecture.check_access_rules(&module_tree).is_ok());
+}
+
+#[test]
+fn may_only_be_accessed_by_regex() {
+    let architecture = Architecture::new(hash_set![
+        "regex:^crate::layer_2$".to_owned(),
+        "layer_1".to_owned()
+    ])
+    .with_access_rule(MayOnlyBeAccessedBy::new(
+        "regex:^crate::layer_2$".to_owned(),
+        hash_set!["layer_1".to_owned()],
+        false,
+    ));
+    let module_tree =
+        ModuleTree::new("src/analyzer/tests/access_rules/may_access_same_parent/main.rs");
+    assert!(architecture.check_access_rules(&module_tree).is_ok());
+}
+
+#[test]
+fn fluent_rule() {
+    let architecture = Architecture::new(hash_set![
+        "file_1".to_owned(),
+        "file_2".to_owned(),
+        "file_3".to_owned()
+    ])
+    .with_access_rule(
+        modules()
+            .that()
+            .reside_in("file_2")
+            .should()
+            .only_be_accessed_by(["file_1"])
+            .because("file_2 is an implementation detail of file_1"),
+    );
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    let report = architecture
+        .check_all_access_rules(&module_tree)
+        .err()
+        .unwrap();
+    assert_eq!(report.len(), 1);
+    let violation = report.violations().next().unwrap();
+    assert_eq!(violation.rule_name(), "MayOnlyBeAccessedBy");
+    assert_eq!(
+        violation.description().unwrap(),
+        "modules that reside in `file_2` should only be accessed by `file_1`"
+    );
+    assert_eq!(
+        violation.reason().unwrap(),
+        "file_2 is an implementation detail of file_1"
+    );
+    report.print(module_tree.tree());
+}
+
+#[test]
+fn fluent_rule_several_layers_and_conditions() {
+    let architecture = Architecture::new(hash_set![
+        "file_1".to_owned(),
+        "file_2".to_owned(),
+        "file_3".to_owned()
+    ])
+    .with_access_rule(
+        modules()
+            .that()
+            .reside_in("file_1")
+            .or()
+            .reside_in("file_3")
+            .should()
+            .not_access(["file_2"])
+            .and()
+            .not_be_accessed_by(["file_2"]),
+    );
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    let report = architecture
+        .check_all_access_rules(&module_tree)
+        .err()
+        .unwrap();
+    assert_eq!(report.len(), 2);
+    assert!(report.violations().all(|violation| violation.reason().is_none()
+        && violation.description().unwrap()
+            == "modules that reside in `file_1` or `file_3` should not access `file_2` and not be accessed by `file_2`"));
+}
+
+#[test]
+fn fluent_rule_positive() {
+    let architecture = Architecture::new(hash_set![
+        "file_1".to_owned(),
+        "file_2".to_owned(),
+        "file_3".to_owned()
+    ])
+    .with_access_rule(
+        modules()
+            .that()
+            .reside_in("file_2")
+            .should()
+            .only_be_accessed_by(["file_1", "file_3"])
+            .and()
+            .only_access(Vec::<String>::new()),
+    );
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    assert!(architecture.check_all_access_rules(&module_tree).is_ok());
+}
+
+#[test]
+fn fluent_rule_alternative_conditions() {
+    let architecture = Architecture::new(hash_set![
+        "file_1".to_owned(),
+        "file_2".to_owned(),
+        "file_3".to_owned()
+    ])
+    .with_access_rule(
+        modules()
+            .that()
+            .reside_in("file_1")
+            .or()
+            .reside_in("file_3")
+            .should()
+            .not_access(["file_2"])
+            .or()
+            .not_be_accessed_by(["file_3"]),
+    );
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    assert!(architecture.check_all_access_rules(&module_tree).is_ok());
+
+    let architecture = Architecture::new(hash_set![
+        "file_1".to_owned(),
+        "file_2".to_owned(),
+        "file_3".to_owned()
+    ])
+    .with_access_rule(
+        modules()
+            .that()
+            .reside_in("file_1")
+            .should()
+            .not_access(["file_2"])
+            .or()
+            .only_access(["file_3"]),
+    );
+    let report = architecture
+        .check_all_access_rules(&module_tree)
+        .err()
+        .unwrap();
+    assert_eq!(report.len(), 1);
+    assert!(report
+        .violations()
+        .all(|violation| {
+            violation.description().unwrap()
+        == "modules that reside in `file_1` should not access `file_2` or only access `file_3`"
+        }));
+}
+
+#[test]
+fn fluent_rule_empty_selection() {
+    let architecture = Architecture::new(hash_set!["file_1".to_owned(), "file_2".to_owned()])
+        .with_access_rule(modules().that().should().not_access(["file_2"]));
+    let violation = architecture.validate_access_rules().err().unwrap();
+    assert_eq!(
+        violation.violation_type(),
+        RuleViolationType::LayerDoNotExist
+    );
+
+    let architecture = Architecture::new(hash_set!["file_1".to_owned(), "file_2".to_owned()])
+        .with_access_rule(
+            modules()
+                .that()
+                .reside_in("file_1")
+                .should()
+                .not_access(["file_2"])
+                .or(),
+        );
+    assert!(architecture.validate_access_rules().is_err());
+}
+
+#[test]
+fn fluent_rule_unknown_layer() {
+    let architecture = Architecture::new(hash_set!["file_1".to_owned()]).with_access_rule(
+        modules()
+            .that()
+            .reside_in("file_1")
+            .should()
+            .not_access(["file_4"]),
+    );
+    assert!(architecture.validate_access_rules().is_err());
+}
+
+#[test]
+fn invalid_layer_selector() {
+    let architecture = Architecture::new(hash_set!["file_1".to_owned(), "regex:(".to_owned()])
+        .with_access_rule(NoParentAccess)
+        .with_access_rule(MayNotAccess::new(
+            "file_1".to_owned(),
+            hash_set!["regex:(".to_owned()],
+            false,
+        ));
+    let violation = architecture.validate_access_rules().err().unwrap();
+    assert_eq!(
+        violation.violation_type(),
+        RuleViolationType::LayerDoNotExist
+    );
+    assert_eq!(violation.rule_index(), Some(1));
+    assert_eq!(
+        violation.description().unwrap(),
+        "The layer selector `regex:(` is no valid regular expression"
+    );
+
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    assert!(architecture
+        .check_complete_layer_specification(&module_tree)
+        .is_err());
+}
+
+#[test]
+fn layered_architecture_relaxed() {
+    let architecture = Architecture::new(hash_set![
+        "api".to_owned(),
+        "services".to_owned(),
+        "repository".to_owned()
+    ])
+    .with_access_rule(LayeredArchitecture::new(
+        vec![
+            "api".to_owned(),
+            "services".to_owned(),
+            "repository".to_owned(),
+        ],
+        false,
+    ));
+    let module_tree =
+        ModuleTree::new("src/analyzer/tests/access_rules/layered_architecture/main.rs");
+    assert!(architecture.validate_access_rules().is_ok());
+    assert!(architecture.check_access_rules(&module_tree).is_ok());
+}
+
+#[test]
+fn layered_architecture_strict() {
+    let architecture = Architecture::new(hash_set![
+        "api".to_owned(),
+        "services".to_owned(),
+        "repository".to_owned()
+    ])
+    .with_access_rule(LayeredArchitecture::new(
+        vec![
+            "api".to_owned(),
+            "services".to_owned(),
+            "repository".to_owned(),
+        ],
+        true,
+    ));
+    let module_tree =
+        ModuleTree::new("src/analyzer/tests/access_rules/layered_architecture/main.rs");
+    let report = architecture
+        .check_all_access_rules(&module_tree)
+        .err()
+        .unwrap();
+    assert_eq!(report.len(), 1);
+    let violation = report.violations().next().unwrap();
+    assert_eq!(
+        violation.description().unwrap(),
+        "Layer `api` may only access the layer directly below it (`services`), but accesses `repository`"
+    );
+    assert_eq!(
+        module_tree.tree()[violation.involved_object_uses()[0]
+            .using_object()
+            .node_index()]
+        .module_name(),
+        "api"
+    );
+    report.print(module_tree.tree());
+}
+
+#[test]
+fn layered_architecture_upwards() {
+    let architecture = Architecture::new(hash_set![
+        "api".to_owned(),
+        "services".to_owned(),
+        "repository".to_owned()
+    ])
+    .with_access_rule(LayeredArchitecture::new(
+        vec![
+            "repository".to_owned(),
+            "services".to_owned(),
+            "api".to_owned(),
+        ],
+        false,
+    ));
+    let module_tree =
+        ModuleTree::new("src/analyzer/tests/access_rules/layered_architecture/main.rs");
+    let report = architecture
+        .check_all_access_rules(&module_tree)
+        .err()
+        .unwrap();
+    assert_eq!(report.len(), 3);
+    assert!(report
+        .violations()
+        .any(|violation| violation.description().unwrap()
+            == "Layer `services` may not access the higher layer `repository`"));
+    report.print(module_tree.tree());
+}
+
+#[test]
+fn onion_architecture() {
+    let architecture = Architecture::new(hash_set![
+        "domain_model".to_owned(),
+        "domain_service".to_owned(),
+        "application".to_owned(),
+        "persistence".to_owned(),
+        "web".to_owned()
+    ])
+    .with_access_rule(OnionArchitecture::new(
+        vec!["domain_model".to_owned()],
+        vec!["domain_service".to_owned()],
+        vec!["application".to_owned()],
+        vec!["persistence".to_owned(), "web".to_owned()],
+    ));
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/onion_architecture/main.rs");
+    assert!(architecture.validate_access_rules().is_ok());
+    let report = architecture
+        .check_all_access_rules(&module_tree)
+        .err()
+        .unwrap();
+    let mut descriptions: Vec<&String> = report
+        .violations()
+        .map(|violation| violation.description().unwrap())
+        .collect();
+    descriptions.sort();
+    assert_eq!(
+        descriptions,
+        vec![
+            "Adapter `persistence` may not access adapter `web`",
+            "Domain model `domain_model` may not access application service `application`"
+        ]
+    );
+    report.print(module_tree.tree());
+}
+
+#[test]
+fn onion_architecture_positive() {
+    let architecture = Architecture::new(hash_set![
+        "domain_service".to_owned(),
+        "application".to_owned(),
+        "web".to_owned()
+    ])
+    .with_access_rule(OnionArchitecture::new(
+        vec![],
+        vec!["domain_service".to_owned()],
+        vec!["application".to_owned()],
+        vec!["web".to_owned()],
+    ));
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/onion_architecture/main.rs");
+    assert!(architecture.check_access_rules(&module_tree).is_ok());
+}
+
+#[test]
+fn check_all_access_rules() {
+    let architecture = Architecture::new(hash_set![
+        "file_1".to_owned(),
+        "file_2".to_owned(),
+        "file_3".to_owned()
+    ])
+    .with_access_rule(MayOnlyBeAccessedBy::new(
+        "file_2".to_owned(),
+        hash_set![],
+        false,
+    ))
+    .with_access_rule(NoParentAccess)
+    .with_access_rule(MayNotAccess::new(
+        "file_1".to_owned(),
+        hash_set!["file_2".to_owned()],
+        false,
+    ));
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    let report = architecture
+        .check_all_access_rules(&module_tree)
+        .err()
+        .unwrap();
+    assert_eq!(report.groups().len(), 2);
+    assert_eq!(report.groups()[0].len(), 2);
+    assert_eq!(report.groups()[1].len(), 1);
+    assert_eq!(report.len(), 3);
+    report.print(module_tree.tree());
+}
+
+#[test]
+fn check_all_access_rules_positive() {
+    let architecture =
+        Architecture::new(hash_set!["file_1".to_owned(), "file_2".to_owned()]).with_access_rule(
+            MayOnlyAccess::new("file_1".to_owned(), hash_set!["file_2".to_owned()], false),
+        );
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    assert!(architecture.check_all_access_rules(&module_tree).is_ok());
+}
+
+#[test]
+fn severities() {
+    let architecture = Architecture::new(hash_set![
+        "file_1".to_owned(),
+        "file_2".to_owned(),
+        "file_3".to_owned()
+    ])
+    .with_access_rule_and_severity(
+        MayOnlyBeAccessedBy::new("file_2".to_owned(), hash_set![], false),
+        Severity::Off,
+    )
+    .with_access_rule_and_severity(
+        MayNotAccess::new("file_1".to_owned(), hash_set!["file_2".to_owned()], false),
+        Severity::Warning,
+    );
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    assert!(architecture.check_access_rules(&module_tree).is_ok());
+    let report = architecture
+        .check_all_access_rules(&module_tree)
+        .err()
+        .unwrap();
+    assert_eq!(report.len(), 1);
+    assert_eq!(report.warning_count(), 1);
+    assert!(!report.has_errors());
+    report.print(module_tree.tree());
+}
+
+#[test]
+fn external_layer_may_not_access() {
+    let architecture = Architecture::new(hash_set!["domain".to_owned()])
+        .with_external_layer(
+            "infrastructure".to_owned(),
+            hash_set!["diesel".to_owned(), "sqlx".to_owned()],
+        )
+        .with_access_rule(MayNotAccess::new(
+            "domain".to_owned(),
+            hash_set!["infrastructure".to_owned()],
+            false,
+        ));
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/external_crates/main.rs");
+    assert!(architecture.validate_access_rules().is_ok());
+    let violation = architecture.check_access_rules(&module_tree).err().unwrap();
+    assert_eq!(
+        violation.involved_object_uses()[0]
+            .used_object()
+            .full_module_path(),
+        "diesel::PgConnection"
+    );
+    violation.print(module_tree.tree());
+}
+
+#[test]
+fn external_layer_declared_after_rule() {
+    let architecture = Architecture::new(hash_set!["domain".to_owned()])
+        .with_access_rule(MayNotAccess::new(
+            "domain".to_owned(),
+            hash_set!["infrastructure".to_owned()],
+            false,
+        ))
+        .with_external_layer("infrastructure".to_owned(), hash_set!["diesel".to_owned()]);
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/external_crates/main.rs");
+    assert!(architecture.validate_access_rules().is_ok());
+    assert!(architecture.check_access_rules(&module_tree).is_err());
+}
+
+#[test]
+fn external_crate_may_not_access() {
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/external_crates/main.rs");
+    let architecture =
+        Architecture::new(hash_set!["adapters".to_owned(), "diesel".to_owned()]).with_access_rule(
+            MayNotAccess::new("adapters".to_owned(), hash_set!["diesel".to_owned()], false),
+        );
+    assert!(architecture.check_access_rules(&module_tree).is_ok());
+
+    let architecture = Architecture::new(hash_set!["adapters".to_owned()])
+        .with_external_layer("sqlx".to_owned(), hash_set!["sqlx".to_owned()])
+        .with_access_rule(MayNotAccess::new(
+            "adapters".to_owned(),
+            hash_set!["sqlx".to_owned()],
+            false,
+        ));
+    assert!(architecture.check_access_rules(&module_tree).is_err());
+}
+
+#[test]
+fn external_layer_may_only_access() {
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/external_crates/main.rs");
+    let architecture = Architecture::new(hash_set!["domain".to_owned()]).with_access_rule(
+        MayOnlyAccess::new("domain".to_owned(), hash_set!["domain".to_owned()], false),
+    );
+    assert!(architecture.check_access_rules(&module_tree).is_ok());
+
+    let architecture = Architecture::new(hash_set!["domain".to_owned()])
+        .with_external_layer("infrastructure".to_owned(), hash_set!["diesel".to_owned()])
+        .with_access_rule(MayOnlyAccess::new(
+            "domain".to_owned(),
+            hash_set!["domain".to_owned()],
+            false,
+        ));
+    assert!(architecture.check_access_rules(&module_tree).is_err());
+}
+
+#[test]
+fn item_may_not_access() {
+    let architecture = Architecture::new(hash_set![]).with_access_rule(ItemMayNotAccess::new(
+        ItemSelector::new("Order"),
+        vec![ItemSelector::new("PaymentGateway")],
+    ));
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/item_access/main.rs");
+    assert!(architecture.validate_access_rules().is_ok());
+    let violation = architecture.check_access_rules(&module_tree).err().unwrap();
+    assert_eq!(
+        violation.description().unwrap(),
+        "Item `Order` may not access `PaymentGateway`"
+    );
+    violation.print(module_tree.tree());
+
+    let architecture = Architecture::new(hash_set![]).with_access_rule(ItemMayNotAccess::new(
+        ItemSelector::new("*").with_kind(ObjectType::Struct),
+        vec![ItemSelector::new("PaymentGateway")],
+    ));
+    let report = architecture
+        .check_all_access_rules(&module_tree)
+        .err()
+        .unwrap();
+    assert_eq!(report.len(), 2);
+}
+
+#[test]
+fn item_may_not_access_ignores_self_use() {
+    let architecture = Architecture::new(hash_set![]).with_access_rule(ItemMayNotAccess::new(
+        ItemSelector::new("Order"),
+        vec![ItemSelector::new("Order")],
+    ));
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/item_access/main.rs");
+    assert!(architecture.check_access_rules(&module_tree).is_ok());
+}
+
+#[test]
+fn item_may_only_be_accessed_by() {
+    let architecture = Architecture::new(hash_set!["domain".to_owned()])
+        .with_external_layer("sqlx".to_owned(), hash_set!["sqlx".to_owned()])
+        .with_access_rule(ItemMayOnlyBeAccessedBy::new(
+            ItemSelector::new("*").with_layer("sqlx"),
+            vec![ItemSelector::new("*Repository").with_kind(ObjectType::Struct)],
+        ));
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/item_access/main.rs");
+    assert!(architecture.validate_access_rules().is_ok());
+    let report = architecture
+        .check_all_access_rules(&module_tree)
+        .err()
+        .unwrap();
+    assert_eq!(report.len(), 2);
+    assert!(report.violations().all(|violation| violation
+        .description()
+        .unwrap()
+        .ends_with("but is accessed by `connect`")));
+
+    let architecture = Architecture::new(hash_set!["domain".to_owned()])
+        .with_external_layer("sqlx".to_owned(), hash_set!["sqlx".to_owned()])
+        .with_access_rule(ItemMayOnlyBeAccessedBy::new(
+            ItemSelector::new("*").with_layer("sqlx"),
+            vec![
+                ItemSelector::new("*Repository").with_kind(ObjectType::Struct),
+                ItemSelector::new("connect").with_kind(ObjectType::Function),
+            ],
+        ));
+    assert!(architecture.check_access_rules(&module_tree).is_ok());
+}
+
+#[test]
+fn item_selector_layer_does_not_exist() {
+    let architecture = Architecture::new(hash_set!["domain".to_owned()]).with_access_rule(
+        ItemMayOnlyBeAccessedBy::new(
+            ItemSelector::new("Order").with_layer("domain"),
+            vec![ItemSelector::new("*Service").with_layer("services")],
+        ),
+    );
+    assert!(architecture.validate_access_rules().is_err());
+}
+
+#[test]
+fn naming_convention_must_match() {
+    let architecture = Architecture::new(hash_set!["services".to_owned(), "ports".to_owned()])
+        .with_access_rule(NamingConvention::new(
+            "services".to_owned(),
+            ObjectType::Struct,
+            "*Service".to_owned(),
+            true,
+        ))
+        .with_access_rule(NamingConvention::new(
+            "ports".to_owned(),
+            ObjectType::Trait,
+            "*Port".to_owned(),
+            true,
+        ));
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/naming_convention/main.rs");
+    assert!(architecture.validate_access_rules().is_ok());
+    let report = architecture
+        .check_all_access_rules(&module_tree)
+        .err()
+        .unwrap();
+    assert_eq!(report.len(), 2);
+    let declarations: Vec<(&str, &str)> = report
+        .violations()
+        .map(|violation| {
+            let location = &violation.locations()[0];
+            (
+                location.module_path().as_str(),
+                location.object_name().as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        declarations,
+        vec![
+            ("crate::services", "BillingHelper"),
+            ("crate::ports", "Notifier")
+        ]
+    );
+    report.print(module_tree.tree());
+}
+
+#[test]
+fn naming_convention_must_not_match() {
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/naming_convention/main.rs");
+    let architecture = Architecture::new(hash_set!["domain_values".to_owned()]).with_access_rule(
+        NamingConvention::new(
+            "domain_values".to_owned(),
+            ObjectType::Function,
+            "*".to_owned(),
+            false,
+        ),
+    );
+    let violation = architecture.check_access_rules(&module_tree).err().unwrap();
+    assert_eq!(
+        violation.description().unwrap(),
+        "Function `round` in `domain_values` must not match `*`"
+    );
+
+    let architecture = Architecture::new(hash_set!["domain_values".to_owned()]).with_access_rule(
+        NamingConvention::new(
+            "domain_values".to_owned(),
+            ObjectType::Struct,
+            "*Service".to_owned(),
+            false,
+        ),
+    );
+    assert!(architecture.check_access_rules(&module_tree).is_ok());
+}
+
+#[test]
+fn visibility_at_most() {
+    let architecture = Architecture::new(hash_set!["internal".to_owned()])
+        .with_access_rule(VisibilityRule::at_most(
+            "internal".to_owned(),
+            ObjectType::Struct,
+            Visibility::Crate,
+        ))
+        .with_access_rule(VisibilityRule::at_most(
+            "internal".to_owned(),
+            ObjectType::Function,
+            Visibility::Crate,
+        ));
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/visibility/main.rs");
+    assert!(architecture.validate_access_rules().is_ok());
+    let report = architecture
+        .check_all_access_rules(&module_tree)
+        .err()
+        .unwrap();
+    let descriptions: Vec<&str> = report
+        .violations()
+        .map(|violation| violation.description().unwrap().as_str())
+        .collect();
+    assert_eq!(
+        descriptions,
+        vec![
+            "Struct `Engine` in `internal` is pub, but must be at most pub(crate)",
+            "Function `start` in `internal` is pub, but must be at most pub(crate)"
+        ]
+    );
+    report.print(module_tree.tree());
+}
+
+#[test]
+fn visibility_at_least() {
+    let architecture = Architecture::new(hash_set!["domain_values".to_owned()]).with_access_rule(
+        VisibilityRule::at_least(
+            "domain_values".to_owned(),
+            ObjectType::Struct,
+            Visibility::Public,
+        ),
+    );
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/visibility/main.rs");
+    let violation = architecture.check_access_rules(&module_tree).err().unwrap();
+    assert_eq!(
+        violation.locations()[0].module_path(),
+        "crate::domain_values"
+    );
+    assert_eq!(violation.locations()[0].object_name(), "Currency");
+
+    let architecture = Architecture::new(hash_set!["domain_values".to_owned()]).with_access_rule(
+        VisibilityRule::at_least(
+            "domain_values".to_owned(),
+            ObjectType::Enum,
+            Visibility::Super,
+        ),
+    );
+    assert!(architecture.check_access_rules(&module_tree).is_ok());
+}
+
+#[test]
+fn forbidden_paths() {
+    let architecture =
+        Architecture::new(hash_set!["domain".to_owned()]).with_access_rule(ForbiddenPaths::new(
+            "domain".to_owned(),
+            hash_set![
+                "std::process::exit".to_owned(),
+                "std::fs".to_owned(),
+                "println!".to_owned(),
+                "panic!".to_owned(),
+                "dbg!".to_owned(),
+                "Instant::now".to_owned()
+            ],
+        ));
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/forbidden_paths/main.rs");
+    assert!(architecture.validate_access_rules().is_ok());
+    let report = architecture
+        .check_all_access_rules(&module_tree)
+        .err()
+        .unwrap();
+    let paths: Vec<&str> = report
+        .violations()
+        .map(|violation| violation.locations()[0].object_name().as_str())
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "std::fs",
+            "println",
+            "std::time::Instant::now",
+            "std::fs::read_to_string",
+            "dbg",
+            "std::process::exit",
+            "panic"
+        ]
+    );
+    assert!(report
+        .violations()
+        .all(|violation| violation.locations()[0].node_index() == 1));
+    report.print(module_tree.tree());
+}
+
+#[test]
+fn forbidden_paths_patterns() {
+    let forbidden_paths = ForbiddenPaths::new(
+        "domain".to_owned(),
+        hash_set!["std::*::exit".to_owned(), "**::time::Instant".to_owned()],
+    );
+    assert!(forbidden_paths
+        .find_matching_pattern("std::process::exit")
+        .is_some());
+    assert!(forbidden_paths
+        .find_matching_pattern("std::time::Instant::now")
+        .is_some());
+    assert!(forbidden_paths
+        .find_matching_pattern("crate::process::exit_code")
+        .is_none());
+}
+
+#[test]
+fn unsafe_only_in() {
+    let architecture = Architecture::new(hash_set!["ffi".to_owned()])
+        .with_access_rule(UnsafeOnlyIn::new(hash_set!["ffi".to_owned()]));
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/unsafe_code/main.rs");
+    assert!(architecture.validate_access_rules().is_ok());
+    let report = architecture
+        .check_all_access_rules(&module_tree)
+        .err()
+        .unwrap();
+    assert_eq!(report.len(), 1);
+    let violation = report.violations().next().unwrap();
+    assert_eq!(
+        violation.description().unwrap(),
+        "unsafe block in `crate::domain` is not within the layers `ffi`"
+    );
+    assert!(violation.involved_object_uses().is_empty());
+    assert_eq!(violation.locations()[0].module_path(), "crate::domain");
+    assert_eq!(violation.locations()[0].object_name(), "unsafe block");
+    report.print(module_tree.tree());
+
+    let architecture =
+        Architecture::new(hash_set!["ffi".to_owned(), "domain".to_owned()]).with_access_rule(
+            UnsafeOnlyIn::new(hash_set!["ffi".to_owned(), "domain".to_owned()]),
+        );
+    assert!(architecture.check_access_rules(&module_tree).is_ok());
+}
+
+#[test]
+fn suppressions() {
+    let architecture =
+        Architecture::new(hash_set!["domain".to_owned(), "infrastructure".to_owned()])
+            .with_access_rule(MayNotAccess::new(
+                "domain".to_owned(),
+                hash_set!["infrastructure".to_owned()],
+                true,
+            ));
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/suppressions/main.rs");
+    let report = architecture
+        .check_all_access_rules(&module_tree)
+        .err()
+        .unwrap();
+    assert_eq!(report.groups().len(), 2);
+    let violation = &report.groups()[0][0];
+    assert_eq!(report.groups()[0].len(), 1);
+    assert_eq!(
+        violation.involved_object_uses()[0]
+            .used_object()
+            .full_module_path(),
+        "crate::infrastructure::Database"
+    );
+    let descriptions: Vec<&String> = report.groups()[1]
+        .iter()
+        .filter(|violation| violation.violation_type() == RuleViolationType::UnusedSuppression)
+        .filter_map(|violation| violation.description())
+        .collect();
+    assert_eq!(
+        descriptions,
+        vec![
+            "The suppression refers to the unknown rule `NoParentAccess`",
+            "The suppression of `MayNotAccess` does not suppress any violation"
+        ]
+    );
+}
+
+#[test]
+fn suppressions_first_violation() {
+    let architecture =
+        Architecture::new(hash_set!["domain".to_owned(), "infrastructure".to_owned()])
+            .with_access_rule(MayNotAccess::new(
+                "domain".to_owned(),
+                hash_set!["infrastructure".to_owned()],
+                true,
+            ))
+            .with_access_rule(NoParentAccess);
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/suppressions/main.rs");
+    let violation = architecture.check_access_rules(&module_tree).err().unwrap();
+    assert_eq!(
+        violation.involved_object_uses()[0]
+            .used_object()
+            .full_module_path(),
+        "crate::infrastructure::Database"
+    );
+}
+
 #[test]
 fn myself() {
     let architecture = Architecture::new(hash_set![
@@ -0,0 +1,7 @@
+pub mod model;
+
+use self::model::Model;
+
+pub struct Domain {
+    model: Model,
+}
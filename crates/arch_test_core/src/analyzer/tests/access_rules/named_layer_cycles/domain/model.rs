@@ -0,0 +1,7 @@
+use crate::domain::Domain;
+
+pub struct Model;
+
+pub fn wrap(domain: Domain) -> Domain {
+    domain
+}
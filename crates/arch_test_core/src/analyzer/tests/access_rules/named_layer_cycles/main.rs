@@ -0,0 +1,3 @@
+mod domain;
+mod materials;
+mod services;
@@ -0,0 +1,7 @@
+use crate::services::Service;
+
+pub struct Material;
+
+pub fn create(service: &Service) -> Material {
+    Material
+}
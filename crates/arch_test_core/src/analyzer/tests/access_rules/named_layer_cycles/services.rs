@@ -0,0 +1,5 @@
+use crate::materials::Material;
+
+pub struct Service {
+    material: Material,
+}
@@ -0,0 +1,7 @@
+pub struct Money {
+    cents: u64,
+}
+
+fn round(money: Money) -> Money {
+    money
+}
@@ -0,0 +1,3 @@
+mod domain_values;
+mod ports;
+mod services;
@@ -0,0 +1,3 @@
+pub trait PaymentPort {}
+
+pub trait Notifier {}
@@ -0,0 +1,3 @@
+pub struct OrderService;
+
+pub struct BillingHelper;
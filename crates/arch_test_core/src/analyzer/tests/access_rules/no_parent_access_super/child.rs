@@ -0,0 +1 @@
+fn child_exec(parent: super::Parent) {}
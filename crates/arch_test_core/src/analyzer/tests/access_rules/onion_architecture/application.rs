@@ -0,0 +1,5 @@
+use crate::domain_service::OrderService;
+
+pub struct App;
+
+fn run(service: OrderService) {}
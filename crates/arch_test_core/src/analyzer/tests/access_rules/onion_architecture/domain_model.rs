@@ -0,0 +1,5 @@
+use crate::application::App;
+
+pub struct Order;
+
+fn start(app: App) {}
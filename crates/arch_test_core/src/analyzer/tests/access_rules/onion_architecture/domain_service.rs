@@ -0,0 +1,5 @@
+use crate::domain_model::Order;
+
+pub struct OrderService;
+
+fn process(order: Order) {}
@@ -0,0 +1,5 @@
+mod application;
+mod domain_model;
+mod domain_service;
+mod persistence;
+mod web;
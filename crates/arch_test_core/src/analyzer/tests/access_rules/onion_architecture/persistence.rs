@@ -0,0 +1,6 @@
+use crate::domain_model::Order;
+use crate::web::Controller;
+
+pub struct OrderRepository;
+
+fn store(order: Order, controller: Controller) {}
@@ -0,0 +1,5 @@
+use crate::application::App;
+
+pub struct Controller;
+
+fn serve(app: App) {}
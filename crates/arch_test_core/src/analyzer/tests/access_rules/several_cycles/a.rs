@@ -0,0 +1,5 @@
+use crate::b::B;
+
+pub struct A {
+    b: B,
+}
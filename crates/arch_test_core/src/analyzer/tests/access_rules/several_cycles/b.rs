@@ -0,0 +1,5 @@
+use crate::a::A;
+
+pub struct B {
+    a: A,
+}
@@ -0,0 +1,5 @@
+use crate::d::D;
+
+pub struct C {
+    d: D,
+}
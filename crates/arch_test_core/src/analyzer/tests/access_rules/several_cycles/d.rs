@@ -0,0 +1,5 @@
+use crate::e::E;
+
+pub struct D {
+    e: E,
+}
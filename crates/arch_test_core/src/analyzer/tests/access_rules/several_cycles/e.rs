@@ -0,0 +1,7 @@
+use crate::c::C;
+use crate::d::D;
+
+pub struct E {
+    c: C,
+    d: D,
+}
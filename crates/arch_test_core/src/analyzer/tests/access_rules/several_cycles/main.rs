@@ -0,0 +1,5 @@
+mod a;
+mod b;
+mod c;
+mod d;
+mod e;
@@ -0,0 +1,11 @@
+// archtest-allow(MayNotAccess): reading the time is a deliberate exception
+use crate::infrastructure::Clock;
+use crate::infrastructure::Database;
+
+// archtest-allow(NoParentAccess): nothing to suppress
+pub struct Order {
+    database: Database,
+}
+
+// archtest-allow(MayNotAccess)
+pub fn now(clock: &Clock) {}
@@ -0,0 +1,3 @@
+pub struct Clock;
+
+pub struct Database;
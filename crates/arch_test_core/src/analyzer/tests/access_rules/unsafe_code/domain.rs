@@ -0,0 +1,5 @@
+use crate::ffi::absolute;
+
+pub fn distance(from: i32, to: i32) -> i32 {
+    unsafe { absolute(to - from) }
+}
@@ -0,0 +1,7 @@
+extern "C" {
+    fn abs(input: i32) -> i32;
+}
+
+pub unsafe fn absolute(input: i32) -> i32 {
+    abs(input)
+}
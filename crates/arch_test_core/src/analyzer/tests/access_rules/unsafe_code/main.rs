@@ -0,0 +1,2 @@
+mod domain;
+mod ffi;
@@ -0,0 +1,8 @@
+pub struct Money;
+
+struct Currency;
+
+pub(super) enum Rounding {
+    Up,
+    Down,
+}
@@ -0,0 +1,7 @@
+pub struct Engine;
+
+pub(crate) struct Gear;
+
+struct Bolt;
+
+pub fn start() {}
@@ -0,0 +1,2 @@
+mod domain_values;
+mod internal;
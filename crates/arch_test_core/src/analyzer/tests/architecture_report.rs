@@ -0,0 +1,246 @@
+use std::error::Error;
+
+use ra_ap_syntax::{TextRange, TextSize};
+use velcro::hash_set;
+
+use crate::analyzer::domain_values::access_rules::{
+    LayeredArchitecture, MayNotAccess, NoParentAccess, VisibilityRule,
+};
+use crate::analyzer::domain_values::{
+    modules, LineIndex, ParameterValue, RuleViolationType, Severity, SourceSpan, ViolatedRule,
+};
+use crate::analyzer::entities::{ArchitectureReport, SourceFiles};
+use crate::parser::domain_values::{ObjectType, Visibility};
+use crate::{Architecture, ModuleTree};
+
+#[test]
+fn architecture_report() {
+    let architecture = Architecture::new(hash_set!["file_1".to_owned(), "file_2".to_owned()])
+        .with_access_rule_and_severity(
+            MayNotAccess::new("file_1".to_owned(), hash_set!["file_2".to_owned()], false),
+            Severity::Warning,
+        );
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    let report = architecture
+        .check_all_access_rules(&module_tree)
+        .err()
+        .unwrap();
+    let architecture_report = ArchitectureReport::new(report.violations(), module_tree.tree())
+        .with_rules(architecture.rule_records());
+    assert_eq!(architecture_report.rules().len(), 1);
+    assert_eq!(architecture_report.rules()[0].rule().kind(), "MayNotAccess");
+    assert_eq!(architecture_report.rules()[0].severity(), Severity::Warning);
+    assert_eq!(architecture_report.error_count(), 0);
+    assert_eq!(architecture_report.warning_count(), 1);
+
+    let violation = &architecture_report.violations()[0];
+    assert_eq!(violation.rule().kind(), "MayNotAccess");
+    assert_eq!(violation.rule_index(), Some(0));
+    assert_eq!(
+        violation.rule().parameters()["accessor"],
+        ParameterValue::text("file_1")
+    );
+    assert_eq!(
+        violation.violation_type(),
+        RuleViolationType::SingleLocation
+    );
+    assert_eq!(violation.severity(), Severity::Warning);
+
+    let accessor = violation.uses()[0].accessor();
+    assert_eq!(
+        accessor.file().unwrap(),
+        "src/analyzer/tests/access_rules/may_access/file_1.rs"
+    );
+    assert_eq!(accessor.module_path(), "crate::file_1");
+    assert_eq!(accessor.object_kind(), ObjectType::Use);
+    assert_eq!(accessor.span(), Some(SourceSpan::new(1, 5, 1, 25)));
+    let accessed = violation.uses()[0].accessed();
+    assert_eq!(accessed.module_path(), "crate::file_2");
+    assert_eq!(accessed.object_name(), "Test2");
+    assert_eq!(accessed.object_kind(), ObjectType::Struct);
+}
+
+#[test]
+fn rule_descriptor() {
+    let rule_descriptor = MayNotAccess::new(
+        "parser".to_owned(),
+        hash_set!["analyzer".to_owned(), "a, b".to_owned()],
+        true,
+    )
+    .descriptor();
+    assert_eq!(rule_descriptor.kind(), "MayNotAccess");
+    assert_eq!(rule_descriptor.parameters().len(), 3);
+    assert_eq!(
+        rule_descriptor.parameters()["accessor"],
+        ParameterValue::text("parser")
+    );
+    assert_eq!(
+        rule_descriptor.parameters()["accessed"],
+        ParameterValue::List(vec!["a, b".to_owned(), "analyzer".to_owned()])
+    );
+    assert_eq!(
+        rule_descriptor.parameters()["when_same_parent"],
+        ParameterValue::Bool(true)
+    );
+
+    let rule_descriptor = NoParentAccess.descriptor();
+    assert_eq!(rule_descriptor.kind(), "NoParentAccess");
+    assert!(rule_descriptor.parameters().is_empty());
+}
+
+#[test]
+fn line_index() {
+    let line_index = LineIndex::new("fn a() {}\n\nfn bä() {\r\n    c();\n}".to_owned());
+    let range = |start: u32, end: u32| TextRange::new(TextSize::from(start), TextSize::from(end));
+    assert_eq!(line_index.line_count(), 5);
+    assert_eq!(line_index.line(3), Some("fn bä() {"));
+    assert_eq!(line_index.line(5), Some("}"));
+    assert_eq!(line_index.line(6), None);
+    assert_eq!(
+        line_index.span(&range(0, 9)),
+        Some(SourceSpan::new(1, 1, 1, 10))
+    );
+    assert_eq!(
+        line_index.span(&range(27, 30)),
+        Some(SourceSpan::new(4, 5, 4, 8))
+    );
+    assert_eq!(
+        line_index.span(&range(11, 33)),
+        Some(SourceSpan::new(3, 1, 5, 2))
+    );
+    assert_eq!(line_index.span(&range(30, 40)), None);
+}
+
+#[test]
+fn render_violation() {
+    let architecture =
+        Architecture::new(hash_set!["file_1".to_owned(), "file_2".to_owned()]).with_access_rule(
+            MayNotAccess::new("file_1".to_owned(), hash_set!["file_2".to_owned()], false),
+        );
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    let report = architecture
+        .check_all_access_rules(&module_tree)
+        .err()
+        .unwrap();
+    let violation = report.violations().next().unwrap();
+    let mut source_files = SourceFiles::new();
+    assert_eq!(
+        violation.render(module_tree.tree(), &mut source_files, false),
+        [
+            "error[MayNotAccess]: `crate::file_1` accesses `crate::file_2::Test2`",
+            " --> src/analyzer/tests/access_rules/may_access/file_1.rs:1:5",
+            "  |",
+            "1 | use crate::file_2::Test2;",
+            "  |     ^^^^^^^^^^^^^^^^^^^^ accessed here",
+            "  |",
+            " ::: src/analyzer/tests/access_rules/may_access/file_2.rs:1:12",
+            "  |",
+            "1 | pub struct Test2;",
+            "  |            ----- `Test2` is defined here",
+            "  |",
+            "  = rule: modules in `file_1` may not access `file_2`",
+            "",
+        ]
+        .join("\n")
+    );
+    let rendered = violation.render(module_tree.tree(), &mut source_files, true);
+    assert!(rendered.starts_with("\u{1b}[1;31merror[MayNotAccess]\u{1b}[0m"));
+    assert!(rendered.contains("\u{1b}[1;31m^^^^^^^^^^^^^^^^^^^^ accessed here\u{1b}[0m"));
+}
+
+#[test]
+fn render_report() {
+    let architecture = Architecture::new(hash_set![
+        "file_1".to_owned(),
+        "file_2".to_owned(),
+        "file_3".to_owned()
+    ])
+    .with_access_rule(MayNotAccess::new(
+        "file_1".to_owned(),
+        hash_set!["file_2".to_owned()],
+        false,
+    ))
+    .with_access_rule_and_severity(
+        MayNotAccess::new("file_3".to_owned(), hash_set!["file_2".to_owned()], false),
+        Severity::Warning,
+    );
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    let report = architecture
+        .check_all_access_rules(&module_tree)
+        .err()
+        .unwrap();
+    let rendered = report.render(module_tree.tree(), false);
+    let headers: Vec<&str> = rendered
+        .lines()
+        .filter(|line| line.contains(" of MayNotAccess: "))
+        .collect();
+    assert_eq!(
+        headers,
+        vec![
+            "1 violation(s) of MayNotAccess: modules in `file_1` may not access `file_2`",
+            "1 warning(s) of MayNotAccess: modules in `file_3` may not access `file_2`",
+        ]
+    );
+    assert!(rendered.ends_with("Found 2 violation(s) in total, 1 of them are warnings.\n"));
+}
+
+#[test]
+fn rule_display() {
+    assert_eq!(
+        MayNotAccess::new(
+            "parser".to_owned(),
+            hash_set!["analyzer".to_owned(), "materials".to_owned()],
+            true
+        )
+        .to_string(),
+        "modules in `parser` may not access `analyzer`, `materials` (within the same parent)"
+    );
+    assert_eq!(
+        LayeredArchitecture::new(
+            vec![
+                "adapters".to_owned(),
+                "services".to_owned(),
+                "domain".to_owned()
+            ],
+            true
+        )
+        .to_string(),
+        "the layers `adapters`, `services`, `domain` may only access the layer directly below them"
+    );
+    assert_eq!(
+        VisibilityRule::at_most("internal".to_owned(), ObjectType::Struct, Visibility::Crate)
+            .to_string(),
+        "Struct objects in `internal` must be at most `pub(crate)`"
+    );
+    assert_eq!(
+        modules()
+            .that()
+            .reside_in("services")
+            .should()
+            .not_access(["tests"])
+            .to_string(),
+        "modules that reside in `services` should not access `tests`"
+    );
+}
+
+#[test]
+fn violation_display() {
+    let architecture = Architecture::new(hash_set!["file_1".to_owned(), "file_2".to_owned()])
+        .with_access_rule(
+            modules()
+                .that()
+                .reside_in("file_1")
+                .should()
+                .not_access(["file_2"])
+                .because("file_2 is internal"),
+        );
+    let module_tree = ModuleTree::new("src/analyzer/tests/access_rules/may_access/main.rs");
+    let check = || -> Result<(), Box<dyn Error + '_>> {
+        architecture.check_access_rules(&module_tree)?;
+        Ok(())
+    };
+    assert_eq!(
+        check().unwrap_err().to_string(),
+        "error: modules that reside in `file_1` should not access `file_2` (`crate::file_1` -> `crate::file_2::Test2`) (reason: file_2 is internal)"
+    );
+}
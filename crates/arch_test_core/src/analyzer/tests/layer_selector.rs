@@ -0,0 +1,94 @@
+use crate::analyzer::domain_values::LayerSelector;
+use crate::analyzer::services::matches_layer;
+use crate::parser::entities::ModuleNode;
+
+fn tree() -> Vec<ModuleNode> {
+    let mut root = ModuleNode::new(0, "main.rs".to_owned(), 0, None, "crate".to_owned());
+    let mut billing = ModuleNode::new(1, "billing".to_owned(), 1, Some(0), "billing".to_owned());
+    let mut invoices = ModuleNode::new(2, "invoices".to_owned(), 2, Some(1), "invoices".to_owned());
+    let services = ModuleNode::new(3, "services".to_owned(), 3, Some(2), "services".to_owned());
+    let mut adapters = ModuleNode::new(4, "adapters".to_owned(), 1, Some(0), "adapters".to_owned());
+    let db = ModuleNode::new(5, "db".to_owned(), 2, Some(4), "db".to_owned());
+    root.register_child(1);
+    root.register_child(4);
+    billing.register_child(2);
+    invoices.register_child(3);
+    adapters.register_child(5);
+    vec![root, billing, invoices, services, adapters, db]
+}
+
+#[test]
+fn module_name() {
+    let tree = tree();
+    let selector = LayerSelector::new("invoices");
+    assert!(!matches_layer(&selector, 1, &tree));
+    assert!(matches_layer(&selector, 2, &tree));
+    assert!(matches_layer(&selector, 3, &tree));
+    assert!(selector.matches_path("crate::billing::invoices"));
+    assert!(!selector.matches_path("crate::billing::invoices::services"));
+}
+
+#[test]
+fn path_pattern() {
+    let tree = tree();
+    let selector = LayerSelector::new("crate::billing::*::services");
+    assert!(matches_layer(&selector, 3, &tree));
+    assert!(!matches_layer(&selector, 2, &tree));
+    assert!(!matches_layer(&selector, 5, &tree));
+
+    let selector = LayerSelector::new("crate::**::db");
+    assert!(matches_layer(&selector, 5, &tree));
+    assert!(!matches_layer(&selector, 4, &tree));
+
+    let selector = LayerSelector::new("crate::**");
+    assert!(tree
+        .iter()
+        .all(|node| matches_layer(&selector, node.index(), &tree)));
+
+    let selector = LayerSelector::new("crate::bill*::**::serv*");
+    assert!(matches_layer(&selector, 3, &tree));
+    assert!(!matches_layer(&selector, 2, &tree));
+}
+
+#[test]
+fn relative_path_pattern() {
+    let tree = tree();
+    let selector = LayerSelector::new("*voices");
+    assert!(!matches_layer(&selector, 1, &tree));
+    assert!(matches_layer(&selector, 2, &tree));
+    assert!(matches_layer(&selector, 3, &tree));
+    assert!(selector.matches_path("crate::billing::invoices"));
+
+    let selector = LayerSelector::new("invoices::serv*");
+    assert!(matches_layer(&selector, 3, &tree));
+    assert!(!matches_layer(&selector, 2, &tree));
+
+    let selector = LayerSelector::new("billing::*");
+    assert!(matches_layer(&selector, 2, &tree));
+    assert!(!matches_layer(&selector, 1, &tree));
+    assert!(!matches_layer(&selector, 5, &tree));
+}
+
+#[test]
+fn regex() {
+    let tree = tree();
+    let selector = LayerSelector::new("regex:^crate::(billing|adapters)$");
+    assert!(matches_layer(&selector, 1, &tree));
+    assert!(matches_layer(&selector, 3, &tree));
+    assert!(matches_layer(&selector, 5, &tree));
+    assert!(!matches_layer(&selector, 0, &tree));
+}
+
+#[test]
+fn equality() {
+    assert_eq!(
+        LayerSelector::new("crate::**::db"),
+        LayerSelector::parse("crate::**::db").unwrap()
+    );
+    assert_eq!(LayerSelector::new("services").as_str(), "services");
+    assert!(LayerSelector::parse("regex:^crate::(billing$").is_err());
+    assert_eq!(
+        format!("{:?}", LayerSelector::new("services")),
+        "\"services\""
+    );
+}
@@ -1 +1,3 @@
 mod access_rules;
+mod architecture_report;
+mod layer_selector;
@@ -2,9 +2,26 @@
 //! ArchTest is a rule based architecture testing tool. It applies static analyses on the specified rust project to extract use relationships.
 //!
 //! ## Features
-//! * Detect cyclic dependencies level wise or module wise
+//! * Detect cyclic dependencies level wise or module wise. `check_all_access_rules` reports every group of dependent modules with a shortest cycle.
+//! * Detect cycles between named layers like `services -> materials -> services` with `NoNamedLayerCyclicDependencies`.
 //! * Prohibit parent access
 //! * Define layer relationships like `MayNotAccess`, `MayOnlyAccess`, `MyNotBeAccessedBy`, `MayOnlyBeAccessedBy` etc.
+//! * Define a `LayeredArchitecture` from an ordered list of layers, optionally strict.
+//! * Define an `OnionArchitecture` from its domain model, domain services, application services and adapters.
+//! * Declare external crates as layers, e.g. `infrastructure` for `diesel` and `sqlx`, and restrict access to them like to any other layer.
+//! * Evaluate `cfg` attributes for a set of features, `test` and target options, see `CfgSet`.
+//! * Restrict the dependencies of individual structs, enums, traits, functions and type aliases with `ItemMayNotAccess` and `ItemMayOnlyBeAccessedBy`, see `ItemSelector`.
+//! * Enforce naming conventions per layer and object type with `NamingConvention`.
+//! * Restrict the visibility of the objects of a layer with `VisibilityRule`, e.g. nothing in `internal` is `pub` beyond `pub(crate)`.
+//! * Forbid paths like `std::fs` or `println!` in a layer with `ForbiddenPaths`.
+//! * Restrict `unsafe` code and `extern` blocks to designated layers with `UnsafeOnlyIn`.
+//! * Suppress individual violations with `// archtest-allow(MayNotAccess): reason` in front of or behind the code, or with `#[cfg_attr(archtest, allow(MayNotAccess))]`. Unused suppressions are reported, see `Suppression`.
+//! * Give access rules a `Severity` with `Architecture::with_access_rule_and_severity`. Warnings are reported, but do not fail `check_access_rules`.
+//! * Write rules fluently like `modules().that().reside_in("services").should().only_be_accessed_by(["tests"]).because("...")`, conditions are combined with `and()` and `or()` and the reason is carried into the violations, see `FluentRule`.
+//! * Violations are printed like compiler errors with the source lines of the involved objects, see `ViolationRecord::render`.
+//! * Access rules implement `Display` with sentences like "modules in `parser` may not access `analyzer`". `RuleViolation` implements `Display` and `std::error::Error`, so it can be propagated with `?`.
+//! * Describe violations with files, module paths and line/column spans in an `ArchitectureReport`, serializable with the feature `serde`.
+//! * Select layers by module name, by path patterns like `crate::**::adapters::db` or by regular expressions, see `LayerSelector`.
 //! * For more access rules consult `access_rules`.
 //!
 //! ## Install
@@ -34,16 +51,83 @@
 //! ```ignore
 //! architecture.check_access_rules(&module_tree).err().unwrap().print(module_tree.tree());
 //! ```
+//! In order to get every violation of every rule at once, you can request a `ViolationReport`:
+//! ```ignore
+//! architecture.check_all_access_rules(&module_tree).err().unwrap().print(module_tree.tree());
+//! ```
+//!
+//! ## Custom access rules
+//! Project specific rules can be defined by implementing the `AccessRule` trait.
+//! The `ModuleTree` exposes the parsed `ModuleNode`s, which provide the `UseRelation`s of each module.
+//! The rule has to implement `ViolatedRule` as well, which names it for suppressions and baselines, and `Display`, which describes it in the notes of its violations.
+//! ```ignore
+//! #[derive(Debug, Clone)]
+//! struct NoRootAccess;
+//!
+//! impl Display for NoRootAccess {
+//!     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+//!         write!(f, "modules may not access the root module")
+//!     }
+//! }
+//!
+//! impl ViolatedRule for NoRootAccess {
+//!     fn name(&self) -> &'static str {
+//!         "NoRootAccess"
+//!     }
+//! }
+//!
+//! impl AccessRule for NoRootAccess {
+//!     fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation<'_>> {
+//!         for node in module_tree.tree().iter() {
+//!             if let Some(use_relation) = node
+//!                 .use_relations(module_tree.tree(), module_tree.possible_uses(), false)
+//!                 .into_iter()
+//!                 .find(|use_relation| use_relation.used_object().node_index() == 0)
+//!             {
+//!                 return Err(RuleViolation::new(
+//!                     RuleViolationType::SingleLocation,
+//!                     Box::new(self.clone()),
+//!                     vec![use_relation],
+//!                 ));
+//!             }
+//!         }
+//!         Ok(())
+//!     }
+//!
+//!     fn validate(&self, _layer_names: &HashSet<String>) -> bool {
+//!         true
+//!     }
+//! }
+//! ```
 
 extern crate itertools;
 extern crate ra_ap_syntax;
+extern crate regex;
 extern crate velcro;
 
 /// `has_set![...]` macro exposed from the `velcro` crate for utility
 pub use velcro::hash_set;
 
 pub use crate::analyzer::domain_values::access_rules;
+pub use crate::analyzer::domain_values::{
+    modules, ItemSelector, LayerSelector, LineIndex, ModuleSelection, ParameterValue,
+    RuleDescriptor, RuleRecord, RuleViolationType, Severity, SourceSpan, ViolatedRule,
+    ViolationLocation,
+};
+pub use crate::analyzer::entities::{
+    ArchitectureReport, LocationRecord, ObjectRecord, RuleViolation, SourceFiles, UseRecord,
+    ViolationRecord, ViolationReport,
+};
 pub use crate::analyzer::materials::Architecture;
+pub use crate::analyzer::services::{
+    has_parent_matching_name, matches_any, matches_item_scope, matches_layer, matches_used_object,
+    AccessRule,
+};
+pub use crate::parser::domain_values::{
+    CfgPredicate, CfgSet, ItemScope, ObjectType, ObjectUse, Suppression, UnsafeKind, UnsafeSite,
+    UsableObject, UseRelation, Visibility,
+};
+pub use crate::parser::entities::ModuleNode;
 pub use crate::parser::materials::ModuleTree;
 
 mod analyzer;
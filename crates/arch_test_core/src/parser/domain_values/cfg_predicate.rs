@@ -0,0 +1,67 @@
+use crate::parser::domain_values::CfgSet;
+use crate::parser::utils::split_arguments;
+
+/// # A configuration predicate
+/// It is the content of a `#[cfg(...)]` attribute or the first argument of `#[cfg_attr(...)]`,
+/// e.g. `all(unix, not(test), feature = "serde")`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CfgPredicate {
+    Option(String),
+    KeyValue(String, String),
+    All(Vec<CfgPredicate>),
+    Any(Vec<CfgPredicate>),
+    Not(Box<CfgPredicate>),
+}
+
+impl CfgPredicate {
+    /// Parses the predicate. Returns `None` if it is malformed.
+    pub fn parse(predicate: &str) -> Option<Self> {
+        let predicate = predicate.trim();
+        if let Some(position) = predicate.find('=') {
+            if !predicate[..position].contains('(') {
+                return Some(CfgPredicate::KeyValue(
+                    predicate[..position].trim().to_owned(),
+                    predicate[(position + 1)..]
+                        .trim()
+                        .trim_matches('"')
+                        .to_owned(),
+                ));
+            }
+        }
+        if let Some(position) = predicate.find('(') {
+            if !predicate.ends_with(')') {
+                return None;
+            }
+            let arguments = split_arguments(&predicate[(position + 1)..(predicate.len() - 1)])
+                .into_iter()
+                .map(CfgPredicate::parse)
+                .collect::<Option<Vec<CfgPredicate>>>()?;
+            return match predicate[..position].trim() {
+                "all" => Some(CfgPredicate::All(arguments)),
+                "any" => Some(CfgPredicate::Any(arguments)),
+                "not" if arguments.len() == 1 => Some(CfgPredicate::Not(Box::new(
+                    arguments.into_iter().next().unwrap(),
+                ))),
+                _ => None,
+            };
+        }
+        if predicate.is_empty() {
+            return None;
+        }
+        Some(CfgPredicate::Option(predicate.to_owned()))
+    }
+
+    pub fn evaluate(&self, cfg_set: &CfgSet) -> bool {
+        match self {
+            CfgPredicate::Option(option) => cfg_set.contains_option(option),
+            CfgPredicate::KeyValue(key, value) => cfg_set.contains_key_value(key, value),
+            CfgPredicate::All(predicates) => predicates
+                .iter()
+                .all(|predicate| predicate.evaluate(cfg_set)),
+            CfgPredicate::Any(predicates) => predicates
+                .iter()
+                .any(|predicate| predicate.evaluate(cfg_set)),
+            CfgPredicate::Not(predicate) => !predicate.evaluate(cfg_set),
+        }
+    }
+}
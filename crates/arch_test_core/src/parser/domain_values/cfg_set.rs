@@ -0,0 +1,82 @@
+use std::collections::HashSet;
+
+/// # The set of enabled configuration options
+/// It is used to evaluate `#[cfg(...)]` and `#[cfg_attr(...)]` attributes while parsing the `ModuleTree`.
+/// Options are either names like `test` or `unix`, or key value pairs like `feature = "serde"` or `target_os = "linux"`.
+///
+/// Example:
+/// ```ignore
+/// let cfg_set = CfgSet::host().with_feature("serde".to_owned());
+/// let module_tree = ModuleTree::new_with_cfg("src/lib.rs", &cfg_set);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CfgSet {
+    options: HashSet<String>,
+    key_values: HashSet<(String, String)>,
+}
+
+impl CfgSet {
+    /// Creates an empty set, in which neither `test` nor any feature or target option is enabled.
+    pub fn new() -> Self {
+        CfgSet::default()
+    }
+
+    /// Creates a set with the target options of the host, e.g. `unix`, `target_os = "linux"` and `target_pointer_width = "64"`.
+    pub fn host() -> Self {
+        let mut cfg_set = CfgSet::new()
+            .with_option(std::env::consts::FAMILY.to_owned())
+            .with_key_value(
+                "target_family".to_owned(),
+                std::env::consts::FAMILY.to_owned(),
+            )
+            .with_key_value("target_os".to_owned(), std::env::consts::OS.to_owned())
+            .with_key_value("target_arch".to_owned(), std::env::consts::ARCH.to_owned())
+            .with_key_value(
+                "target_pointer_width".to_owned(),
+                (std::mem::size_of::<usize>() * 8).to_string(),
+            )
+            .with_key_value(
+                "target_endian".to_owned(),
+                if cfg!(target_endian = "big") {
+                    "big".to_owned()
+                } else {
+                    "little".to_owned()
+                },
+            );
+        if cfg!(debug_assertions) {
+            cfg_set = cfg_set.with_option("debug_assertions".to_owned());
+        }
+        cfg_set
+    }
+
+    /// Enables `#[cfg(test)]`
+    pub fn with_test(self) -> Self {
+        self.with_option("test".to_owned())
+    }
+
+    /// Enables `#[cfg(feature = "<feature>")]`
+    pub fn with_feature(self, feature: String) -> Self {
+        self.with_key_value("feature".to_owned(), feature)
+    }
+
+    /// Enables `#[cfg(<option>)]`
+    pub fn with_option(mut self, option: String) -> Self {
+        self.options.insert(option);
+        self
+    }
+
+    /// Enables `#[cfg(<key> = "<value>")]`
+    pub fn with_key_value(mut self, key: String, value: String) -> Self {
+        self.key_values.insert((key, value));
+        self
+    }
+
+    pub fn contains_option(&self, option: &str) -> bool {
+        self.options.contains(option)
+    }
+
+    pub fn contains_key_value(&self, key: &str, value: &str) -> bool {
+        self.key_values
+            .contains(&(key.to_owned(), value.to_owned()))
+    }
+}
@@ -0,0 +1,45 @@
+use ra_ap_syntax::TextRange;
+
+use crate::parser::domain_values::ObjectType;
+
+/// # The scope of an item in a module
+/// Describes the text range of a struct, enum, trait, function or type alias declaration, or of an `impl` block.
+/// Uses within this range are part of the item's body.
+/// The object type of an `impl` block is the type of the implemented item, if it is declared within the crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemScope {
+    name: String,
+    object_type: Option<ObjectType>,
+    text_range: TextRange,
+}
+
+impl ItemScope {
+    pub fn new(name: String, object_type: Option<ObjectType>, text_range: TextRange) -> Self {
+        ItemScope {
+            name,
+            object_type,
+            text_range,
+        }
+    }
+
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn object_type(&self) -> Option<ObjectType> {
+        self.object_type
+    }
+
+    pub fn set_object_type(&mut self, object_type: ObjectType) {
+        self.object_type = Some(object_type);
+    }
+
+    pub fn text_range(&self) -> &TextRange {
+        &self.text_range
+    }
+
+    /// Returns `true` if the `text_range` lies within this item
+    pub fn contains(&self, text_range: &TextRange) -> bool {
+        self.text_range.contains_range(*text_range)
+    }
+}
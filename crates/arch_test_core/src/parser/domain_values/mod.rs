@@ -1,9 +1,23 @@
+pub use self::cfg_predicate::CfgPredicate;
+pub use self::cfg_set::CfgSet;
+pub use self::item_scope::ItemScope;
 pub use self::object_type::ObjectType;
 pub use self::object_use::ObjectUse;
+pub use self::suppression::Suppression;
+pub use self::unsafe_kind::UnsafeKind;
+pub use self::unsafe_site::UnsafeSite;
 pub use self::usable_object::UsableObject;
 pub use self::use_relation::UseRelation;
+pub use self::visibility::Visibility;
 
+mod cfg_predicate;
+mod cfg_set;
+mod item_scope;
 mod object_type;
 mod object_use;
+mod suppression;
+mod unsafe_kind;
+mod unsafe_site;
 mod usable_object;
 mod use_relation;
+mod visibility;
@@ -1,4 +1,5 @@
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(u8)]
 pub enum ObjectType {
     Struct,
@@ -9,4 +10,6 @@ pub enum ObjectType {
     Use,
     ImplicitUse,
     TypeAlias,
+    ExternCrate,
+    External,
 }
@@ -0,0 +1,63 @@
+use std::fmt::{Display, Formatter};
+
+use ra_ap_syntax::TextRange;
+
+/// # Suppression of an access rule
+/// Silences the violations of the rule `rule_name` within its `scope`, e.g. a `use` or an item.
+/// It is declared by a comment `// archtest-allow(MayNotAccess): reason` in front of or behind the suppressed code
+/// or by an attribute `#[cfg_attr(archtest, allow(MayNotAccess))]` on it.
+/// The text range is the one of the comment, respectively the attribute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suppression {
+    rule_name: String,
+    reason: Option<String>,
+    text_range: TextRange,
+    scope: TextRange,
+}
+
+impl Suppression {
+    pub fn new(
+        rule_name: String,
+        reason: Option<String>,
+        text_range: TextRange,
+        scope: TextRange,
+    ) -> Self {
+        Suppression {
+            rule_name,
+            reason,
+            text_range,
+            scope,
+        }
+    }
+
+    pub fn rule_name(&self) -> &String {
+        &self.rule_name
+    }
+
+    pub fn reason(&self) -> Option<&String> {
+        self.reason.as_ref()
+    }
+
+    pub fn text_range(&self) -> &TextRange {
+        &self.text_range
+    }
+
+    pub fn scope(&self) -> &TextRange {
+        &self.scope
+    }
+
+    /// Returns `true` if this suppression silences the rule `rule_name` at the `text_range`.
+    pub fn suppresses(&self, rule_name: &str, text_range: &TextRange) -> bool {
+        self.rule_name == rule_name && self.scope.contains_range(*text_range)
+    }
+}
+
+impl Display for Suppression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "a suppression of `{}` has to suppress a violation",
+            self.rule_name
+        )
+    }
+}
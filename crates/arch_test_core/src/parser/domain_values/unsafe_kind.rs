@@ -0,0 +1,23 @@
+use std::fmt::{Display, Formatter};
+
+/// Describes the kind of an `UnsafeSite`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnsafeKind {
+    Block,
+    Function,
+    Impl,
+    Trait,
+    ExternBlock,
+}
+
+impl Display for UnsafeKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnsafeKind::Block => write!(f, "unsafe block"),
+            UnsafeKind::Function => write!(f, "unsafe fn"),
+            UnsafeKind::Impl => write!(f, "unsafe impl"),
+            UnsafeKind::Trait => write!(f, "unsafe trait"),
+            UnsafeKind::ExternBlock => write!(f, "extern block"),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use ra_ap_syntax::TextRange;
+
+use crate::parser::domain_values::UnsafeKind;
+
+/// # An unsafe site in a module
+/// Describes an `unsafe` block, `unsafe fn`, `unsafe impl`, `unsafe trait` or an `extern` block.
+/// The text range is the one of the `unsafe` keyword, respectively the ABI of an `extern` block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsafeSite {
+    kind: UnsafeKind,
+    text_range: TextRange,
+}
+
+impl UnsafeSite {
+    pub fn new(kind: UnsafeKind, text_range: TextRange) -> Self {
+        UnsafeSite { kind, text_range }
+    }
+
+    pub fn kind(&self) -> UnsafeKind {
+        self.kind
+    }
+
+    pub fn text_range(&self) -> &TextRange {
+        &self.text_range
+    }
+}
@@ -2,33 +2,46 @@ use std::hash::{Hash, Hasher};
 
 use ra_ap_syntax::TextRange;
 
-use crate::parser::domain_values::ObjectType;
+use crate::parser::domain_values::{ObjectType, Visibility};
 
 #[derive(Debug, Clone)]
 pub struct UsableObject {
-    is_public: bool,
+    visibility: Visibility,
     object_type: ObjectType,
     pub object_name: String,
     text_range: TextRange,
+    alias: Option<String>,
 }
 
 impl UsableObject {
     pub fn new(
-        is_public: bool,
+        visibility: Visibility,
         object_type: ObjectType,
         object_name: String,
         text_range: TextRange,
     ) -> Self {
         UsableObject {
-            is_public,
+            visibility,
             object_type,
             object_name,
             text_range,
+            alias: None,
         }
     }
 
+    /// Sets the name under which a use statement imports the object, e.g. `R` for `use crate::db::Repo as R;`
+    pub fn with_alias(mut self, alias: String) -> Self {
+        self.alias = Some(alias);
+        self
+    }
+
+    /// Returns `true` if the object is visible outside of its module, i.e. with any `pub` visibility except `pub(self)`
     pub fn is_public(&self) -> bool {
-        self.is_public
+        self.visibility != Visibility::Private
+    }
+
+    pub fn visibility(&self) -> &Visibility {
+        &self.visibility
     }
 
     pub fn object_type(&self) -> ObjectType {
@@ -42,6 +55,10 @@ impl UsableObject {
     pub fn text_range(&self) -> &TextRange {
         &self.text_range
     }
+
+    pub fn alias(&self) -> Option<&String> {
+        self.alias.as_ref()
+    }
 }
 
 impl Hash for UsableObject {
@@ -0,0 +1,77 @@
+use std::fmt::{Display, Formatter};
+
+/// # The visibility of a declared object
+/// Visibilities are ordered from `Private` to `Public`, i.e. `Private < InPath < Super < Crate < Public`.
+/// `pub(in crate)`, `pub(in super)` and `pub(self)` are normalized to `Crate`, `Super` and `Private`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Visibility {
+    Private,
+    InPath(String),
+    Super,
+    Crate,
+    Public,
+}
+
+impl Visibility {
+    /// Parses a visibility as written in source code, e.g. `pub(crate)` or `pub(in crate::domain)`, like in a specification.
+    /// Returns `None` if the text is not a visibility, e.g. `protected` or `pub(crate`.
+    pub fn parse(visibility: &str) -> Option<Self> {
+        let visibility = visibility.trim();
+        if visibility == "crate" {
+            return Some(Visibility::Crate);
+        }
+        let restriction = visibility.strip_prefix("pub")?.trim_start();
+        if restriction.is_empty() {
+            return Some(Visibility::Public);
+        }
+        let restriction = restriction.strip_prefix('(')?.strip_suffix(')')?.trim();
+        match restriction {
+            "crate" => return Some(Visibility::Crate),
+            "super" => return Some(Visibility::Super),
+            "self" => return Some(Visibility::Private),
+            _ => {}
+        }
+        match restriction.strip_prefix("in ")?.trim() {
+            "crate" => Some(Visibility::Crate),
+            "super" => Some(Visibility::Super),
+            "self" => Some(Visibility::Private),
+            path if path.split("::").all(is_identifier) => {
+                Some(Visibility::InPath(path.to_owned()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the position of this visibility in the order from `Private` (0) to `Public` (4).
+    pub fn rank(&self) -> u8 {
+        match self {
+            Visibility::Private => 0,
+            Visibility::InPath(_) => 1,
+            Visibility::Super => 2,
+            Visibility::Crate => 3,
+            Visibility::Public => 4,
+        }
+    }
+}
+
+impl Display for Visibility {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Visibility::Private => write!(f, "private"),
+            Visibility::InPath(path) => write!(f, "pub(in {})", path),
+            Visibility::Super => write!(f, "pub(super)"),
+            Visibility::Crate => write!(f, "pub(crate)"),
+            Visibility::Public => write!(f, "pub"),
+        }
+    }
+}
+
+fn is_identifier(segment: &str) -> bool {
+    segment
+        .chars()
+        .next()
+        .is_some_and(|character| character.is_alphabetic() || character == '_')
+        && segment
+            .chars()
+            .all(|character| character.is_alphanumeric() || character == '_')
+}
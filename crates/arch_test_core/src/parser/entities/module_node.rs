@@ -1,6 +1,10 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::parser::domain_values::{ObjectType, ObjectUse, UsableObject, UseRelation};
+use ra_ap_syntax::TextRange;
+
+use crate::parser::domain_values::{
+    ItemScope, ObjectType, ObjectUse, Suppression, UnsafeSite, UsableObject, UseRelation,
+};
 
 #[derive(Debug, Clone)]
 pub struct ModuleNode {
@@ -10,7 +14,11 @@ pub struct ModuleNode {
     file_path: String,
     module_name: String,
     children: Vec<usize>,
+    is_external: bool,
     pub usable_objects: Vec<UsableObject>,
+    pub item_scopes: Vec<ItemScope>,
+    pub unsafe_sites: Vec<UnsafeSite>,
+    pub suppressions: Vec<Suppression>,
 }
 
 impl ModuleNode {
@@ -28,7 +36,29 @@ impl ModuleNode {
             file_path,
             module_name,
             children: vec![],
+            is_external: false,
+            usable_objects: vec![],
+            item_scopes: vec![],
+            unsafe_sites: vec![],
+            suppressions: vec![],
+        }
+    }
+
+    /// Creates a node that represents an external crate.
+    /// External nodes have no parent and no file and do not use any objects themselves.
+    pub fn new_external(index: usize, module_name: String) -> Self {
+        ModuleNode {
+            index,
+            parent_index: None,
+            level: 0,
+            file_path: String::new(),
+            module_name,
+            children: vec![],
+            is_external: true,
             usable_objects: vec![],
+            item_scopes: vec![],
+            unsafe_sites: vec![],
+            suppressions: vec![],
         }
     }
 
@@ -67,6 +97,51 @@ impl ModuleNode {
         obj_uses
     }
 
+    /// Returns a use relation for every use in this module, without merging uses of the same object.
+    /// Uses of a path into an object, e.g. `Order::new`, are related to the object itself.
+    pub fn use_relations_per_use(
+        &self,
+        tree: &[Self],
+        possible_use_map: &HashMap<String, ObjectUse>,
+    ) -> Vec<UseRelation> {
+        let mut obj_uses = Vec::new();
+        for obj in self.usable_objects.iter().filter(|obj| {
+            obj.object_type() == ObjectType::RePublish
+                || obj.object_type() == ObjectType::Use
+                || obj.object_type() == ObjectType::ImplicitUse
+        }) {
+            let mut path = obj.object_name.as_str();
+            loop {
+                if let Some(obj_use) = possible_use_map.get(path) {
+                    obj_uses.push(UseRelation::new(
+                        ObjectUse::new(
+                            self.index,
+                            self.get_fully_qualified_path(tree),
+                            obj.clone(),
+                        ),
+                        obj_use.clone(),
+                    ));
+                    break;
+                }
+                match path.rfind("::") {
+                    Some(position) => path = &path[..position],
+                    None => break,
+                }
+            }
+        }
+        obj_uses
+    }
+
+    /// Returns the scopes of the items, whose body contains the `text_range`
+    pub fn item_scopes_containing<'a>(
+        &'a self,
+        text_range: &'a TextRange,
+    ) -> impl Iterator<Item = &'a ItemScope> {
+        self.item_scopes
+            .iter()
+            .filter(move |item_scope| item_scope.contains(text_range))
+    }
+
     pub fn get_fully_qualified_path(&self, tree: &[Self]) -> String {
         let mut name = self.module_name.clone();
         let mut parent_index = self.parent_index;
@@ -109,7 +184,25 @@ impl ModuleNode {
         &self.children
     }
 
+    /// Returns `true` if the node represents an external crate, see `ModuleTree`.
+    /// Rules that walk the modules of the crate skip external nodes, they are only targets of use relations.
+    pub fn is_external(&self) -> bool {
+        self.is_external
+    }
+
     pub fn usable_objects(&self) -> &Vec<UsableObject> {
         &self.usable_objects
     }
+
+    pub fn item_scopes(&self) -> &Vec<ItemScope> {
+        &self.item_scopes
+    }
+
+    pub fn unsafe_sites(&self) -> &Vec<UnsafeSite> {
+        &self.unsafe_sites
+    }
+
+    pub fn suppressions(&self) -> &Vec<Suppression> {
+        &self.suppressions
+    }
 }
@@ -1,7 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
-use crate::parser::domain_values::{ObjectType, ObjectUse, UsableObject};
+use ra_ap_syntax::{TextRange, TextSize};
+
+use crate::parser::domain_values::{CfgSet, ObjectType, ObjectUse, UsableObject, Visibility};
 use crate::parser::entities::ModuleNode;
 use crate::parser::services::parse_main_or_mod_file_into_tree;
 
@@ -11,14 +13,34 @@ use crate::parser::services::parse_main_or_mod_file_into_tree;
 /// ```ignore
 /// let module_tree = ModuleTree::new("src/lib.rs");
 /// ```
-#[derive(Debug)]
+///
+/// Paths into external crates, e.g. `diesel::PgConnection`, are kept as use relations.
+/// Each external crate is represented by an external `ModuleNode` without a parent, see `ModuleNode::is_external`.
+/// Crates of the Rust distribution like `std` and `core` are not represented.
+#[derive(Debug, Clone)]
 pub struct ModuleTree {
     tree: Vec<ModuleNode>,
     possible_uses: HashMap<String, ObjectUse>,
 }
 
 impl ModuleTree {
+    /// Parses the `ModuleTree` without evaluating `cfg` attributes, i.e. every item and module is included.
     pub fn new(root_directory: &str) -> Self {
+        ModuleTree::parse(root_directory, None)
+    }
+
+    /// Parses the `ModuleTree` and only includes the items, use statements and modules that are enabled by the `cfg_set`.
+    ///
+    /// Example:
+    /// ```ignore
+    /// // The production build with the feature "postgres"
+    /// let module_tree = ModuleTree::new_with_cfg("src/lib.rs", &CfgSet::host().with_feature("postgres".to_owned()));
+    /// ```
+    pub fn new_with_cfg(root_directory: &str, cfg_set: &CfgSet) -> Self {
+        ModuleTree::parse(root_directory, Some(cfg_set))
+    }
+
+    fn parse(root_directory: &str, cfg_set: Option<&CfgSet>) -> Self {
         let path = Path::new(root_directory);
         assert!(path.exists(), "Expecting a valid path");
         assert!(path.is_file(), "Expecting path to be a file!");
@@ -37,7 +59,14 @@ impl ModuleTree {
             tree: vec![],
             possible_uses: HashMap::default(),
         };
-        parse_main_or_mod_file_into_tree(&mut module_tree.tree, path, 0, None, module_name);
+        parse_main_or_mod_file_into_tree(
+            &mut module_tree.tree,
+            path,
+            0,
+            None,
+            module_name,
+            cfg_set,
+        );
         module_tree.correct_fully_qualified_names();
         module_tree.replace_path_wildcard();
         module_tree.correct_fully_qualified_names();
@@ -46,79 +75,187 @@ impl ModuleTree {
         module_tree.filter_unused_uses();
         module_tree.filter_covered_implicit_uses();
         module_tree.construct_possible_use_map();
+        module_tree.add_external_crates();
+        module_tree.complete_impl_scopes();
         module_tree
     }
 
-    fn correct_fully_qualified_names(&mut self) {
-        let fully_qualified_names: Vec<String> = self
-            .tree
+    /// Resolves a `path` as it is written in the module at `node_index` to its fully qualified form.
+    /// It handles `crate`, `self` and `super` segments, a leading `::`, aliases of use statements and `extern crate` items,
+    /// paths relative to child modules and, for use statements, 2015 edition paths relative to the crate root.
+    /// Paths that can not be resolved, e.g. paths into external crates, are returned unchanged.
+    pub fn resolve_path(&self, node_index: usize, path: &str, is_use_path: bool) -> String {
+        if let Some(path) = path.strip_prefix("::") {
+            let path = self.substitute_extern_crate_alias(path);
+            if self.is_child_module(0, path.split("::").next().unwrap()) {
+                return format!(
+                    "{}::{}",
+                    self.tree[0].get_fully_qualified_path(&self.tree),
+                    path
+                );
+            }
+            return path;
+        }
+
+        let path = self.substitute_alias(node_index, path);
+        let segments: Vec<&str> = path.split("::").collect();
+        let mut module_index = node_index;
+        let mut is_anchored = false;
+        let mut position = 0;
+        while position < segments.len() {
+            match segments[position] {
+                "crate" if position == 0 => module_index = 0,
+                "self" => {}
+                "super" => {
+                    module_index = self.tree[module_index]
+                        .parent_index()
+                        .unwrap_or(module_index)
+                }
+                _ => break,
+            }
+            is_anchored = true;
+            position += 1;
+        }
+        if is_anchored {
+            let module_path = self.tree[module_index].get_fully_qualified_path(&self.tree);
+            if position == segments.len() {
+                return module_path;
+            }
+            return format!("{}::{}", module_path, segments[position..].join("::"));
+        }
+
+        if segments.len() > 1 {
+            if self.is_child_module(node_index, segments[0]) {
+                return format!(
+                    "{}::{}",
+                    self.tree[node_index].get_fully_qualified_path(&self.tree),
+                    path
+                );
+            }
+            if is_use_path && self.is_child_module(0, segments[0]) {
+                return format!(
+                    "{}::{}",
+                    self.tree[0].get_fully_qualified_path(&self.tree),
+                    path
+                );
+            }
+        }
+        path
+    }
+
+    fn is_child_module(&self, node_index: usize, module_name: &str) -> bool {
+        self.tree[node_index]
+            .children()
             .iter()
-            .map(|node| node.get_fully_qualified_path(&self.tree))
-            .collect();
-        let module_names: Vec<String> = self
-            .tree
+            .any(|child_index| self.tree[*child_index].module_name() == module_name)
+    }
+
+    /// Replaces the first segment of the `path`, if it is an alias introduced by a use statement of the module
+    /// or by an `extern crate` item of the crate root.
+    fn substitute_alias(&self, node_index: usize, path: &str) -> String {
+        let mut segments = path.splitn(2, "::");
+        let first_segment = segments.next().unwrap();
+        let target = self.tree[node_index].usable_objects.iter().find(|obj| {
+            (obj.object_type() == ObjectType::Use || obj.object_type() == ObjectType::RePublish)
+                && obj.alias().map(|alias| alias.as_str()) == Some(first_segment)
+                && first_segment != "_"
+                && obj.object_name != path
+        });
+        match (target, segments.next()) {
+            (Some(target), Some(rest)) => format!("{}::{}", target.object_name, rest),
+            (Some(target), None) => target.object_name.clone(),
+            (None, _) => self.substitute_extern_crate_alias(path),
+        }
+    }
+
+    fn substitute_extern_crate_alias(&self, path: &str) -> String {
+        let mut segments = path.splitn(2, "::");
+        let first_segment = segments.next().unwrap();
+        let crate_name = self.tree[0]
+            .usable_objects
             .iter()
-            .map(|node| node.module_name().clone())
-            .collect();
-        for (index, node) in self.tree.iter_mut().enumerate() {
-            let node_children_module_names: Vec<String> = node
-                .children()
-                .iter()
-                .map(|child_index| module_names[*child_index].clone())
-                .collect();
-            for uses in node.usable_objects.iter_mut().filter(|obj| {
-                obj.object_type() == ObjectType::Use || obj.object_type() == ObjectType::RePublish
-            }) {
-                if uses.object_name.starts_with("self::") {
-                    uses.object_name = uses
-                        .object_name
-                        .replace("self::", &format!("{}::", fully_qualified_names[index]));
-                } else if !uses.object_name.starts_with("crate::") {
-                    let has_mod_prefix = node_children_module_names
-                        .iter()
-                        .any(|module_name| uses.object_name.starts_with(module_name));
-                    if has_mod_prefix {
-                        uses.object_name =
-                            format!("{}::{}", fully_qualified_names[index], uses.object_name);
-                    }
+            .find(|obj| {
+                obj.object_type() == ObjectType::ExternCrate
+                    && obj.alias().map(|alias| alias.as_str()) == Some(first_segment)
+            })
+            .map(|obj| {
+                if obj.object_name == "self" {
+                    "crate"
+                } else {
+                    obj.object_name.as_str()
                 }
-            }
+            });
+        match (crate_name, segments.next()) {
+            (Some(crate_name), Some(rest)) => format!("{}::{}", crate_name, rest),
+            (Some(crate_name), None) => crate_name.to_owned(),
+            (None, _) => path.to_owned(),
+        }
+    }
 
-            let use_paths: Vec<String> = node
+    fn correct_fully_qualified_names(&mut self) {
+        for index in 0..self.tree.len() {
+            let resolved_names: Vec<String> = self.tree[index]
                 .usable_objects
                 .iter()
-                .filter(|obj| {
-                    obj.object_type() == ObjectType::Use
-                        || obj.object_type() == ObjectType::RePublish
+                .map(|obj| match obj.object_type() {
+                    ObjectType::Use | ObjectType::RePublish => {
+                        self.resolve_path(index, &obj.object_name, true)
+                    }
+                    _ => obj.object_name.clone(),
                 })
-                .map(|obj| obj.object_name.clone())
                 .collect();
-            for uses in node
+            let use_paths: Vec<String> = self.tree[index]
                 .usable_objects
-                .iter_mut()
-                .filter(|obj| obj.object_type() == ObjectType::ImplicitUse)
-            {
-                let splits: Vec<&str> = uses.object_name.split("::").collect();
-                if let Some(prefix) = use_paths.iter().find(|prefix| prefix.ends_with(&splits[0])) {
-                    if splits.len() > 1 {
-                        uses.object_name = format!("{}::{}", prefix, splits[1..].join("::"));
+                .iter()
+                .zip(resolved_names.iter())
+                .filter(|(obj, _)| {
+                    (obj.object_type() == ObjectType::Use
+                        || obj.object_type() == ObjectType::RePublish)
+                        && obj.alias().is_none()
+                })
+                .map(|(_, resolved_name)| resolved_name.clone())
+                .collect();
+            let resolved_names: Vec<String> = self.tree[index]
+                .usable_objects
+                .iter()
+                .zip(resolved_names)
+                .map(|(obj, resolved_name)| {
+                    if obj.object_type() == ObjectType::ImplicitUse {
+                        self.resolve_implicit_use(index, &obj.object_name, &use_paths)
                     } else {
-                        uses.object_name = prefix.clone();
+                        resolved_name
                     }
-                } else if let Some(prefix) = use_paths
-                    .iter()
-                    .find(|prefix| prefix.ends_with(&uses.object_name))
-                {
-                    uses.object_name = prefix.clone();
-                } else if splits.len() > 1
-                    && node_children_module_names
-                        .iter()
-                        .any(|name| name == splits[0])
-                {
-                    uses.object_name =
-                        format!("{}::{}", fully_qualified_names[index], uses.object_name);
-                }
+                })
+                .collect();
+            for (obj, resolved_name) in self.tree[index]
+                .usable_objects
+                .iter_mut()
+                .zip(resolved_names)
+            {
+                obj.object_name = resolved_name;
+            }
+        }
+    }
+
+    fn resolve_implicit_use(&self, node_index: usize, path: &str, use_paths: &[String]) -> String {
+        let splits: Vec<&str> = path.split("::").collect();
+        let is_anchored = splits[0].is_empty()
+            || splits[0] == "crate"
+            || splits[0] == "self"
+            || splits[0] == "super"
+            || self.substitute_alias(node_index, splits[0]) != splits[0];
+        if is_anchored {
+            self.resolve_path(node_index, path, false)
+        } else if let Some(prefix) = use_paths.iter().find(|prefix| prefix.ends_with(&splits[0])) {
+            if splits.len() > 1 {
+                format!("{}::{}", prefix, splits[1..].join("::"))
+            } else {
+                prefix.clone()
             }
+        } else if let Some(prefix) = use_paths.iter().find(|prefix| prefix.ends_with(path)) {
+            prefix.clone()
+        } else {
+            self.resolve_path(node_index, path, false)
         }
     }
 
@@ -138,8 +275,12 @@ impl ModuleTree {
                 .filter(|obj| obj.object_type() == ObjectType::RePublish)
             {
                 let split_vec = path_obj.object_name.split("::").collect::<Vec<&str>>();
+                let name = path_obj
+                    .alias()
+                    .map(|alias| alias.as_str())
+                    .unwrap_or_else(|| split_vec.last().unwrap());
                 republish_map.insert(
-                    format!("{}::{}", prefix, split_vec.last().unwrap()),
+                    format!("{}::{}", prefix, name),
                     path_obj.object_name.clone(),
                 );
             }
@@ -171,6 +312,7 @@ impl ModuleTree {
                 obj.object_type() != ObjectType::RePublish
                     && obj.object_type() != ObjectType::Use
                     && obj.object_type() != ObjectType::ImplicitUse
+                    && obj.object_type() != ObjectType::ExternCrate
             }) {
                 let full_path = format!("{}::{}", prefix, path_obj.object_name);
                 self.possible_uses.insert(
@@ -181,11 +323,96 @@ impl ModuleTree {
         }
     }
 
+    fn add_external_crates(&mut self) {
+        let module_names: HashSet<String> = self
+            .tree
+            .iter()
+            .map(|node| node.module_name().clone())
+            .collect();
+        let unresolved_paths: Vec<String> = self
+            .tree
+            .iter()
+            .flat_map(|node| node.usable_objects.iter())
+            .filter(|obj| {
+                obj.object_type() == ObjectType::Use
+                    || obj.object_type() == ObjectType::RePublish
+                    || obj.object_type() == ObjectType::ImplicitUse
+            })
+            .map(|obj| obj.object_name.clone())
+            .filter(|path| !self.possible_uses.contains_key(path))
+            .collect();
+        for path in unresolved_paths {
+            let crate_name = match external_crate_name(&path, &module_names) {
+                Some(crate_name) => crate_name.to_owned(),
+                None => continue,
+            };
+            let node_index = match self
+                .tree
+                .iter()
+                .position(|node| node.is_external() && *node.module_name() == crate_name)
+            {
+                Some(node_index) => node_index,
+                None => {
+                    let node_index = self.tree.len();
+                    self.tree
+                        .push(ModuleNode::new_external(node_index, crate_name));
+                    node_index
+                }
+            };
+            self.possible_uses.insert(
+                path.clone(),
+                ObjectUse::new(
+                    node_index,
+                    path.clone(),
+                    UsableObject::new(
+                        Visibility::Public,
+                        ObjectType::External,
+                        path,
+                        TextRange::empty(TextSize::from(0)),
+                    ),
+                ),
+            );
+        }
+    }
+
+    /// Sets the object type of `impl` blocks to the type of the implemented item,
+    /// which is either declared in the same module or imported into it.
+    fn complete_impl_scopes(&mut self) {
+        let mut object_types = Vec::new();
+        for (index, node) in self.tree.iter().enumerate() {
+            let prefix = node.get_fully_qualified_path(&self.tree);
+            for (scope_index, item_scope) in node.item_scopes.iter().enumerate() {
+                if item_scope.object_type().is_some() {
+                    continue;
+                }
+                let suffix = format!("::{}", item_scope.name());
+                let object_type = self
+                    .possible_uses
+                    .get(&format!("{}{}", prefix, suffix))
+                    .or_else(|| {
+                        node.usable_objects
+                            .iter()
+                            .filter(|obj| {
+                                obj.object_type() == ObjectType::Use
+                                    || obj.object_type() == ObjectType::RePublish
+                                    || obj.object_type() == ObjectType::ImplicitUse
+                            })
+                            .filter(|obj| obj.object_name.ends_with(&suffix))
+                            .find_map(|obj| self.possible_uses.get(&obj.object_name))
+                    })
+                    .map(|obj_use| obj_use.usable_object().object_type())
+                    .filter(|object_type| *object_type != ObjectType::External);
+                if let Some(object_type) = object_type {
+                    object_types.push((index, scope_index, object_type));
+                }
+            }
+        }
+        for (index, scope_index, object_type) in object_types {
+            self.tree[index].item_scopes[scope_index].set_object_type(object_type);
+        }
+    }
+
     fn filter_primary_types(&mut self) {
-        let primary_types = vec![
-            "i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64", "u128", "isize", "usize",
-            "str", "char", "f32", "f64", "bool", "Self", "self", "_",
-        ];
         let object_primary_types = vec![
             "std::collections::HashMap",
             "std::collections::HashSet",
@@ -201,7 +428,10 @@ impl ModuleTree {
 
         for node in self.tree.iter_mut() {
             for i in (0..node.usable_objects.len()).rev() {
-                if primary_types.contains(&node.usable_objects[i].object_name.as_str()) {
+                if node.usable_objects[i].object_type() == ObjectType::ExternCrate {
+                    continue;
+                }
+                if PRIMARY_TYPES.contains(&node.usable_objects[i].object_name.as_str()) {
                     node.usable_objects.remove(i);
                 } else {
                     let splits: Vec<&str> =
@@ -251,7 +481,7 @@ impl ModuleTree {
                             | ObjectType::Enum
                             | ObjectType::Function => {
                                 self.tree[node_index].usable_objects.push(UsableObject::new(
-                                    use_obj.is_public(),
+                                    use_obj.visibility().clone(),
                                     use_obj.object_type(),
                                     format!("{}::{}", path, obj.object_name),
                                     *use_obj.text_range(),
@@ -279,7 +509,9 @@ impl ModuleTree {
     fn filter_covered_implicit_uses(&mut self) {
         for node in self.tree.iter_mut() {
             for i in (0..node.usable_objects.len()).rev() {
-                if node.usable_objects[i].object_name.starts_with("crate::") {
+                if node.usable_objects[i].object_name.starts_with("crate::")
+                    || node.usable_objects[i].object_type() == ObjectType::ExternCrate
+                {
                     continue;
                 }
 
@@ -323,3 +555,36 @@ impl ModuleTree {
         &self.possible_uses
     }
 }
+
+const PRIMARY_TYPES: [&str; 20] = [
+    "i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64", "u128", "isize", "usize", "str",
+    "char", "f32", "f64", "bool", "Self", "self", "_",
+];
+
+/// Crates of the Rust distribution, which are not represented as external crates
+const SYSROOT_CRATES: [&str; 5] = ["std", "core", "alloc", "proc_macro", "test"];
+
+/// Returns the name of the external crate a path points into, e.g. `diesel` for `diesel::PgConnection`.
+/// Paths that start with a module of this crate, a primary type, a type (e.g. `Self::new`) or a crate of the Rust distribution are not external.
+fn external_crate_name<'a>(path: &'a str, module_names: &HashSet<String>) -> Option<&'a str> {
+    let mut segments = path.trim_start_matches("::").split("::");
+    let crate_name = segments.next()?;
+    segments.next()?;
+    let is_crate_name = crate_name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase())
+        && crate_name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !is_crate_name
+        || crate_name == "crate"
+        || crate_name == "super"
+        || PRIMARY_TYPES.contains(&crate_name)
+        || SYSROOT_CRATES.contains(&crate_name)
+        || module_names.contains(crate_name)
+    {
+        return None;
+    }
+    Some(crate_name)
+}
@@ -1,11 +1,17 @@
 use std::fs::DirEntry;
 use std::path::Path;
 
-use ra_ap_syntax::{SourceFile, SyntaxKind, SyntaxNode, SyntaxNodeChildren, TextRange, TextSize};
+use ra_ap_syntax::ast::{self, AstNode, VisibilityKind};
+use ra_ap_syntax::{
+    NodeOrToken, SourceFile, SyntaxKind, SyntaxNode, SyntaxToken, TextRange, TextSize,
+};
 
-use crate::parser::domain_values::{ObjectType, UsableObject};
+use crate::parser::domain_values::{
+    CfgPredicate, CfgSet, ItemScope, ObjectType, Suppression, UnsafeKind, UnsafeSite, UsableObject,
+    Visibility,
+};
 use crate::parser::entities::ModuleNode;
-use crate::parser::utils::read_file_content;
+use crate::parser::utils::{read_file_content, split_arguments};
 
 pub fn parse_main_or_mod_file_into_tree(
     tree: &mut Vec<ModuleNode>,
@@ -13,18 +19,27 @@ pub fn parse_main_or_mod_file_into_tree(
     level: usize,
     parent_index: Option<usize>,
     module_name: String,
+    cfg_set: Option<&CfgSet>,
 ) {
     let mut module_references: Vec<(usize, String, Option<String>)> = Vec::new();
 
     let result = SourceFile::parse(&read_file_content(file_path));
-    parse_syntax_node_tree(
+    if parent_index.is_some() && !is_enabled(&result.syntax_node(), cfg_set) {
+        return;
+    }
+    let current_index = push_module_node(
         tree,
-        result.syntax_node().children(),
         file_path.to_str().unwrap().to_string(),
         level,
         parent_index,
         module_name,
+    );
+    parse_syntax_node_tree(
+        tree,
+        &result.syntax_node(),
+        current_index,
         &mut module_references,
+        cfg_set,
     );
 
     let dir_entries: Vec<DirEntry> = file_path
@@ -53,6 +68,7 @@ pub fn parse_main_or_mod_file_into_tree(
                     tree[parent_index].level() + 1,
                     Some(parent_index),
                     sub_module,
+                    cfg_set,
                 );
             }
         } else if let Some(entry) = dir_entries.iter().find(|entry| {
@@ -74,6 +90,7 @@ pub fn parse_main_or_mod_file_into_tree(
                         tree[parent_index].level() + 1,
                         Some(parent_index),
                         sub_module,
+                        cfg_set,
                     );
                 } else {
                     // Just discover all rust files in this directory
@@ -98,6 +115,7 @@ pub fn parse_main_or_mod_file_into_tree(
                                 tree[parent_index].level() + 1,
                                 Some(current_index),
                                 sub_entry_name.trim_end_matches(".rs").to_owned(),
+                                cfg_set,
                             );
                         }
                     }
@@ -109,25 +127,24 @@ pub fn parse_main_or_mod_file_into_tree(
                     tree[parent_index].level() + 1,
                     Some(parent_index),
                     sub_module,
+                    cfg_set,
                 );
             }
         }
     }
 }
 
-fn parse_syntax_node_tree(
+fn push_module_node(
     tree: &mut Vec<ModuleNode>,
-    syntax_node_children: SyntaxNodeChildren,
     file_path: String,
     level: usize,
     parent_index: Option<usize>,
     module_name: String,
-    module_references: &mut Vec<(usize, String, Option<String>)>,
-) {
+) -> usize {
     let current_index = tree.len();
     tree.push(ModuleNode::new(
         current_index,
-        file_path.clone(),
+        file_path,
         level,
         parent_index,
         module_name,
@@ -137,22 +154,34 @@ fn parse_syntax_node_tree(
             .unwrap()
             .register_child(current_index);
     }
+    current_index
+}
 
-    for item in syntax_node_children {
-        if let Some((inner_module_start_node, inner_module_name)) = parse_file_rec(
-            &item,
-            module_references,
-            &mut tree[current_index].usable_objects,
-            current_index,
-        ) {
-            parse_syntax_node_tree(
+fn parse_syntax_node_tree(
+    tree: &mut Vec<ModuleNode>,
+    syntax_node: &SyntaxNode,
+    current_index: usize,
+    module_references: &mut Vec<(usize, String, Option<String>)>,
+    cfg_set: Option<&CfgSet>,
+) {
+    collect_suppressions(syntax_node, &mut tree[current_index], cfg_set);
+    for item in syntax_node.children() {
+        if let Some((inner_module_start_node, inner_module_name)) =
+            parse_file_rec(&item, module_references, &mut tree[current_index], cfg_set)
+        {
+            let inner_module_index = push_module_node(
                 tree,
-                inner_module_start_node,
-                file_path.clone(),
-                level + 1,
+                tree[current_index].file_path().clone(),
+                tree[current_index].level() + 1,
                 Some(current_index),
                 inner_module_name,
+            );
+            parse_syntax_node_tree(
+                tree,
+                &inner_module_start_node,
+                inner_module_index,
                 module_references,
+                cfg_set,
             );
         }
     }
@@ -161,35 +190,48 @@ fn parse_syntax_node_tree(
 fn parse_file_rec(
     syntax_node: &SyntaxNode,
     module_references: &mut Vec<(usize, String, Option<String>)>,
-    usable_objects: &mut Vec<UsableObject>,
-    current_index: usize,
-) -> Option<(SyntaxNodeChildren, String)> {
+    node: &mut ModuleNode,
+    cfg_set: Option<&CfgSet>,
+) -> Option<(SyntaxNode, String)> {
+    if !is_enabled(syntax_node, cfg_set) {
+        return None;
+    }
+    if let Some(item_scope) = parse_item_scope(syntax_node) {
+        node.item_scopes.push(item_scope);
+    }
+    if let Some(unsafe_site) = parse_unsafe_site(syntax_node) {
+        node.unsafe_sites.push(unsafe_site);
+    }
     match syntax_node.kind() {
         SyntaxKind::USE => {
-            let (is_pub, paths) = parse_use_paths(syntax_node);
-            for (path, text_range) in paths {
-                usable_objects.push(UsableObject::new(
-                    is_pub,
-                    if is_pub {
+            let (visibility, paths) = parse_use_paths(syntax_node);
+            for (path, text_range, alias) in paths {
+                let usable_object = UsableObject::new(
+                    visibility.clone(),
+                    if visibility != Visibility::Private {
                         ObjectType::RePublish
                     } else {
                         ObjectType::Use
                     },
                     path,
                     text_range,
-                ));
+                );
+                node.usable_objects.push(match alias {
+                    Some(alias) => usable_object.with_alias(alias),
+                    None => usable_object,
+                });
             }
         }
         SyntaxKind::STRUCT => {
-            let mut is_pub = false;
+            let mut visibility = Visibility::Private;
             for child in syntax_node.children() {
                 match child.kind() {
                     SyntaxKind::VISIBILITY => {
-                        is_pub = true;
+                        visibility = parse_visibility(&child);
                     }
                     SyntaxKind::NAME => {
-                        usable_objects.push(UsableObject::new(
-                            is_pub,
+                        node.usable_objects.push(UsableObject::new(
+                            visibility.clone(),
                             ObjectType::Struct,
                             child.to_string(),
                             child.text_range(),
@@ -197,8 +239,8 @@ fn parse_file_rec(
                     }
                     SyntaxKind::RECORD_FIELD_LIST => {
                         for (impl_use_path, text_range) in parse_field_list(&child) {
-                            usable_objects.push(UsableObject::new(
-                                is_pub,
+                            node.usable_objects.push(UsableObject::new(
+                                visibility.clone(),
                                 ObjectType::ImplicitUse,
                                 impl_use_path,
                                 text_range,
@@ -212,15 +254,15 @@ fn parse_file_rec(
             }
         }
         SyntaxKind::ENUM => {
-            let mut is_pub = false;
+            let mut visibility = Visibility::Private;
             for child in syntax_node.children() {
                 match child.kind() {
                     SyntaxKind::VISIBILITY => {
-                        is_pub = true;
+                        visibility = parse_visibility(&child);
                     }
                     SyntaxKind::NAME => {
-                        usable_objects.push(UsableObject::new(
-                            is_pub,
+                        node.usable_objects.push(UsableObject::new(
+                            visibility.clone(),
                             ObjectType::Enum,
                             child.to_string(),
                             child.text_range(),
@@ -233,8 +275,8 @@ fn parse_file_rec(
                                     SyntaxKind::TUPLE_FIELD_LIST
                                     | SyntaxKind::RECORD_FIELD_LIST => {
                                         for (impl_use_path, text_range) in parse_field_list(&arg) {
-                                            usable_objects.push(UsableObject::new(
-                                                is_pub,
+                                            node.usable_objects.push(UsableObject::new(
+                                                visibility.clone(),
                                                 ObjectType::ImplicitUse,
                                                 impl_use_path,
                                                 text_range,
@@ -251,15 +293,15 @@ fn parse_file_rec(
             }
         }
         SyntaxKind::FN | SyntaxKind::CLOSURE_EXPR | SyntaxKind::FN_PTR_TYPE => {
-            let mut is_pub = false;
+            let mut visibility = Visibility::Private;
             for child in syntax_node.children() {
                 match child.kind() {
                     SyntaxKind::VISIBILITY => {
-                        is_pub = true;
+                        visibility = parse_visibility(&child);
                     }
                     SyntaxKind::NAME => {
-                        usable_objects.push(UsableObject::new(
-                            is_pub,
+                        node.usable_objects.push(UsableObject::new(
+                            visibility.clone(),
                             ObjectType::Function,
                             child.to_string(),
                             child.text_range(),
@@ -267,8 +309,8 @@ fn parse_file_rec(
                     }
                     SyntaxKind::PARAM_LIST => {
                         for (impl_use_path, text_range) in parse_field_list(&child) {
-                            usable_objects.push(UsableObject::new(
-                                is_pub,
+                            node.usable_objects.push(UsableObject::new(
+                                visibility.clone(),
                                 ObjectType::ImplicitUse,
                                 impl_use_path,
                                 text_range,
@@ -280,8 +322,8 @@ fn parse_file_rec(
                             match ret.kind() {
                                 SyntaxKind::PATH_TYPE => {
                                     for (impl_use_path, text_range) in parse_path_type(&ret) {
-                                        usable_objects.push(UsableObject::new(
-                                            is_pub,
+                                        node.usable_objects.push(UsableObject::new(
+                                            visibility.clone(),
                                             ObjectType::ImplicitUse,
                                             impl_use_path,
                                             text_range,
@@ -293,7 +335,7 @@ fn parse_file_rec(
                         }
                     }
                     SyntaxKind::BLOCK_EXPR => {
-                        parse_file_rec(&child, module_references, usable_objects, current_index);
+                        parse_file_rec(&child, module_references, node, cfg_set);
                     }
                     _ => {
                         continue;
@@ -303,8 +345,8 @@ fn parse_file_rec(
         }
         SyntaxKind::PATH_EXPR | SyntaxKind::TUPLE_STRUCT_PAT | SyntaxKind::PATH_PAT => {
             for (impl_use_path, text_range) in parse_path_type(syntax_node) {
-                usable_objects.push(UsableObject::new(
-                    false,
+                node.usable_objects.push(UsableObject::new(
+                    Visibility::Private,
                     ObjectType::ImplicitUse,
                     impl_use_path,
                     text_range,
@@ -312,24 +354,25 @@ fn parse_file_rec(
             }
         }
         SyntaxKind::TRAIT => {
-            let mut is_pub = false;
+            let mut visibility = Visibility::Private;
             for child in syntax_node.children() {
                 match child.kind() {
                     SyntaxKind::VISIBILITY => {
-                        is_pub = true;
+                        visibility = parse_visibility(&child);
                     }
                     SyntaxKind::NAME => {
-                        usable_objects.push(UsableObject::new(
-                            is_pub,
+                        node.usable_objects.push(UsableObject::new(
+                            visibility.clone(),
                             ObjectType::Trait,
                             child.to_string(),
                             child.text_range(),
                         ));
                     }
                     SyntaxKind::ASSOC_ITEM_LIST => {
+                        collect_unsafe_sites(&child, node, cfg_set);
                         for (impl_use_path, text_range) in parse_assoc_func_item_list(&child) {
-                            usable_objects.push(UsableObject::new(
-                                is_pub,
+                            node.usable_objects.push(UsableObject::new(
+                                visibility.clone(),
                                 ObjectType::ImplicitUse,
                                 impl_use_path,
                                 text_range,
@@ -345,8 +388,8 @@ fn parse_file_rec(
                 match child.kind() {
                     SyntaxKind::PATH_TYPE => {
                         for (impl_use_path, text_range) in parse_path_type(&child) {
-                            usable_objects.push(UsableObject::new(
-                                false,
+                            node.usable_objects.push(UsableObject::new(
+                                Visibility::Private,
                                 ObjectType::ImplicitUse,
                                 impl_use_path,
                                 text_range,
@@ -354,9 +397,10 @@ fn parse_file_rec(
                         }
                     }
                     SyntaxKind::ASSOC_ITEM_LIST => {
+                        collect_unsafe_sites(&child, node, cfg_set);
                         for (impl_use_path, text_range) in parse_assoc_func_item_list(&child) {
-                            usable_objects.push(UsableObject::new(
-                                false,
+                            node.usable_objects.push(UsableObject::new(
+                                Visibility::Private,
                                 ObjectType::ImplicitUse,
                                 impl_use_path,
                                 text_range,
@@ -368,32 +412,23 @@ fn parse_file_rec(
             }
         }
         SyntaxKind::MODULE => {
-            let mut path: Option<String> = None;
+            let path: Option<String> =
+                attributes(syntax_node, cfg_set)
+                    .into_iter()
+                    .find_map(|attribute| {
+                        let value = attribute
+                            .strip_prefix("path")?
+                            .trim_start()
+                            .strip_prefix('=')?;
+                        Some(value.trim().to_owned())
+                    });
             for child in syntax_node.children() {
                 match child.kind() {
-                    SyntaxKind::ATTR => {
-                        let mut found_path = false;
-                        for attr_child in child.children() {
-                            match attr_child.kind() {
-                                SyntaxKind::PATH => {
-                                    if attr_child.to_string() == *"path" {
-                                        found_path = true;
-                                    }
-                                }
-                                SyntaxKind::LITERAL => {
-                                    if found_path {
-                                        path = Some(attr_child.to_string());
-                                    }
-                                }
-                                _ => continue,
-                            }
-                        }
-                    }
                     SyntaxKind::NAME => {
-                        module_references.push((current_index, child.to_string(), path.clone()));
+                        module_references.push((node.index(), child.to_string(), path.clone()));
                     }
                     SyntaxKind::ITEM_LIST => {
-                        return Some((child.children(), module_references.pop().unwrap().1));
+                        return Some((child, module_references.pop().unwrap().1));
                     }
                     _ => continue,
                 }
@@ -401,8 +436,8 @@ fn parse_file_rec(
         }
         SyntaxKind::PARAM_LIST => {
             for (impl_use_path, text_range) in parse_field_list(syntax_node) {
-                usable_objects.push(UsableObject::new(
-                    false,
+                node.usable_objects.push(UsableObject::new(
+                    Visibility::Private,
                     ObjectType::ImplicitUse,
                     impl_use_path,
                     text_range,
@@ -414,8 +449,8 @@ fn parse_file_rec(
         | SyntaxKind::TUPLE_PAT
         | SyntaxKind::SLICE_TYPE => {
             for (impl_use_path, text_range) in parse_nested_tuple_type(syntax_node) {
-                usable_objects.push(UsableObject::new(
-                    false,
+                node.usable_objects.push(UsableObject::new(
+                    Visibility::Private,
                     ObjectType::ImplicitUse,
                     impl_use_path,
                     text_range,
@@ -439,12 +474,7 @@ fn parse_file_rec(
                                         continue;
                                     }
                                     _ => {
-                                        parse_file_rec(
-                                            &arm_item,
-                                            module_references,
-                                            usable_objects,
-                                            current_index,
-                                        );
+                                        parse_file_rec(&arm_item, module_references, node, cfg_set);
                                     }
                                 }
                             }
@@ -458,8 +488,8 @@ fn parse_file_rec(
             for child in syntax_node.children() {
                 match child.kind() {
                     SyntaxKind::PATH => {
-                        usable_objects.push(UsableObject::new(
-                            false,
+                        node.usable_objects.push(UsableObject::new(
+                            Visibility::Private,
                             ObjectType::ImplicitUse,
                             child.to_string(),
                             child.text_range(),
@@ -470,15 +500,15 @@ fn parse_file_rec(
             }
         }
         SyntaxKind::TYPE_ALIAS => {
-            let mut is_pub = false;
+            let mut visibility = Visibility::Private;
             for child in syntax_node.children() {
                 match child.kind() {
                     SyntaxKind::VISIBILITY => {
-                        is_pub = true;
+                        visibility = parse_visibility(&child);
                     }
                     SyntaxKind::NAME => {
-                        usable_objects.push(UsableObject::new(
-                            is_pub,
+                        node.usable_objects.push(UsableObject::new(
+                            visibility.clone(),
                             ObjectType::TypeAlias,
                             child.to_string(),
                             child.text_range(),
@@ -486,8 +516,8 @@ fn parse_file_rec(
                     }
                     SyntaxKind::PATH_TYPE => {
                         for (impl_use_path, text_range) in parse_path_type(&child) {
-                            usable_objects.push(UsableObject::new(
-                                false,
+                            node.usable_objects.push(UsableObject::new(
+                                Visibility::Private,
                                 ObjectType::ImplicitUse,
                                 impl_use_path,
                                 text_range,
@@ -498,10 +528,44 @@ fn parse_file_rec(
                 }
             }
         }
+        SyntaxKind::EXTERN_CRATE => {
+            let mut visibility = Visibility::Private;
+            let mut crate_name: Option<SyntaxNode> = None;
+            let mut alias: Option<String> = None;
+            for child in syntax_node.children() {
+                match child.kind() {
+                    SyntaxKind::VISIBILITY => {
+                        visibility = parse_visibility(&child);
+                    }
+                    SyntaxKind::NAME_REF => {
+                        crate_name = Some(child);
+                    }
+                    SyntaxKind::RENAME => {
+                        alias = child
+                            .children()
+                            .find(|rename_child| rename_child.kind() == SyntaxKind::NAME)
+                            .map(|name| name.to_string());
+                    }
+                    _ => continue,
+                }
+            }
+            if let Some(crate_name) = crate_name {
+                let usable_object = UsableObject::new(
+                    visibility.clone(),
+                    ObjectType::ExternCrate,
+                    crate_name.to_string(),
+                    crate_name.text_range(),
+                );
+                node.usable_objects.push(match alias {
+                    Some(alias) => usable_object.with_alias(alias),
+                    None => usable_object,
+                });
+            }
+        }
         SyntaxKind::GENERIC_ARG_LIST => {
             for (impl_use_path, text_range) in parse_generic_arg_list(syntax_node) {
-                usable_objects.push(UsableObject::new(
-                    false,
+                node.usable_objects.push(UsableObject::new(
+                    Visibility::Private,
                     ObjectType::ImplicitUse,
                     impl_use_path,
                     text_range,
@@ -512,7 +576,6 @@ fn parse_file_rec(
         | SyntaxKind::RANGE_PAT
         | SyntaxKind::DYN_TRAIT_TYPE
         | SyntaxKind::MACRO_DEF
-        | SyntaxKind::RECORD_EXPR
         | SyntaxKind::LIFETIME
         | SyntaxKind::WILDCARD_PAT
         | SyntaxKind::LABEL
@@ -526,12 +589,21 @@ fn parse_file_rec(
         | SyntaxKind::ATTR
         | SyntaxKind::RECORD_PAT
         | SyntaxKind::LITERAL
-        | SyntaxKind::EXTERN_CRATE
         | SyntaxKind::CONTINUE_EXPR
         | SyntaxKind::BREAK_EXPR => {
             return None;
         }
+        SyntaxKind::RECORD_EXPR => {
+            for child in syntax_node
+                .children()
+                .filter(|child| child.kind() == SyntaxKind::RECORD_EXPR_FIELD_LIST)
+            {
+                parse_file_rec(&child, module_references, node, cfg_set);
+            }
+        }
         SyntaxKind::NAME_REF
+        | SyntaxKind::RECORD_EXPR_FIELD_LIST
+        | SyntaxKind::RECORD_EXPR_FIELD
         | SyntaxKind::OR_PAT
         | SyntaxKind::BOX_EXPR
         | SyntaxKind::PTR_TYPE
@@ -566,15 +638,11 @@ fn parse_file_rec(
         | SyntaxKind::ARG_LIST
         | SyntaxKind::EXPR_STMT => {
             for child in syntax_node.children() {
-                parse_file_rec(&child, module_references, usable_objects, current_index);
+                parse_file_rec(&child, module_references, node, cfg_set);
             }
         }
         _ => {
-            println!(
-                "UNHANDLED EXPRESSION: {:?} => {}",
-                syntax_node,
-                syntax_node
-            );
+            println!("UNHANDLED EXPRESSION: {:?} => {}", syntax_node, syntax_node);
             println!(
                 " => Parent: {:?} => {}",
                 syntax_node.parent().unwrap(),
@@ -586,8 +654,276 @@ fn parse_file_rec(
     None
 }
 
-fn parse_use_paths(syntax_node: &SyntaxNode) -> (bool, Vec<(String, TextRange)>) {
-    let mut visibility = false;
+/// Returns the scope of a named item or of an `impl` block.
+/// The object type of an `impl` block is determined later on, as the implemented type may be declared elsewhere.
+fn parse_item_scope(syntax_node: &SyntaxNode) -> Option<ItemScope> {
+    let object_type = match syntax_node.kind() {
+        SyntaxKind::STRUCT => ObjectType::Struct,
+        SyntaxKind::ENUM => ObjectType::Enum,
+        SyntaxKind::FN => ObjectType::Function,
+        SyntaxKind::TRAIT => ObjectType::Trait,
+        SyntaxKind::TYPE_ALIAS => ObjectType::TypeAlias,
+        SyntaxKind::IMPL => {
+            let self_type = syntax_node
+                .children()
+                .filter(|child| child.kind() == SyntaxKind::PATH_TYPE)
+                .last()?;
+            let name = self_type
+                .descendants()
+                .filter(|descendant| descendant.kind() == SyntaxKind::PATH_SEGMENT)
+                .filter(|segment| {
+                    !segment
+                        .ancestors()
+                        .any(|ancestor| ancestor.kind() == SyntaxKind::GENERIC_ARG_LIST)
+                })
+                .last()?
+                .children()
+                .find(|child| child.kind() == SyntaxKind::NAME_REF)?
+                .to_string();
+            return Some(ItemScope::new(name, None, syntax_node.text_range()));
+        }
+        _ => return None,
+    };
+    let name = syntax_node
+        .children()
+        .find(|child| child.kind() == SyntaxKind::NAME)?
+        .to_string();
+    Some(ItemScope::new(
+        name,
+        Some(object_type),
+        syntax_node.text_range(),
+    ))
+}
+
+/// Returns the unsafe site, if the `syntax_node` is an `unsafe` block, fn, impl or trait, or an `extern` block.
+fn parse_unsafe_site(syntax_node: &SyntaxNode) -> Option<UnsafeSite> {
+    let kind = match syntax_node.kind() {
+        SyntaxKind::EXTERN_BLOCK => {
+            let text_range = syntax_node
+                .children()
+                .find(|child| child.kind() == SyntaxKind::ABI)
+                .map_or_else(|| syntax_node.text_range(), |abi| abi.text_range());
+            return Some(UnsafeSite::new(UnsafeKind::ExternBlock, text_range));
+        }
+        SyntaxKind::EFFECT_EXPR => UnsafeKind::Block,
+        SyntaxKind::FN => UnsafeKind::Function,
+        SyntaxKind::IMPL => UnsafeKind::Impl,
+        SyntaxKind::TRAIT => UnsafeKind::Trait,
+        _ => return None,
+    };
+    let unsafe_keyword = syntax_node
+        .children_with_tokens()
+        .find(|child| child.kind() == SyntaxKind::UNSAFE_KW)?;
+    Some(UnsafeSite::new(kind, unsafe_keyword.text_range()))
+}
+
+/// Records the unsafe sites within a `syntax_node` that is not traversed by `parse_file_rec`, e.g. the items of an `impl` block.
+fn collect_unsafe_sites(syntax_node: &SyntaxNode, node: &mut ModuleNode, cfg_set: Option<&CfgSet>) {
+    for child in syntax_node
+        .children()
+        .filter(|child| is_enabled(child, cfg_set))
+    {
+        if let Some(unsafe_site) = parse_unsafe_site(&child) {
+            node.unsafe_sites.push(unsafe_site);
+        }
+        collect_unsafe_sites(&child, node, cfg_set);
+    }
+}
+
+/// Records the suppressions declared within the `syntax_node` by comments and attributes.
+/// An attribute suppresses the node it is attached to, i.e. an inner attribute suppresses the whole module.
+/// Inline modules are skipped, as they record their own suppressions.
+fn collect_suppressions(syntax_node: &SyntaxNode, node: &mut ModuleNode, cfg_set: Option<&CfgSet>) {
+    for element in syntax_node.children_with_tokens() {
+        match element {
+            NodeOrToken::Token(token) => {
+                if token.kind() != SyntaxKind::COMMENT {
+                    continue;
+                }
+                if let Some(scope) = comment_scope(&token) {
+                    node.suppressions.extend(parse_suppression_comment(
+                        token.text(),
+                        token.text_range(),
+                        scope,
+                    ));
+                }
+            }
+            NodeOrToken::Node(child) => {
+                if (syntax_node.kind() == SyntaxKind::MODULE
+                    && child.kind() == SyntaxKind::ITEM_LIST)
+                    || !is_enabled(&child, cfg_set)
+                {
+                    continue;
+                }
+                if child.kind() == SyntaxKind::ATTR {
+                    node.suppressions.extend(parse_suppression_attribute(
+                        &attribute_content(&child),
+                        child.text_range(),
+                        syntax_node.text_range(),
+                    ));
+                    continue;
+                }
+                collect_suppressions(&child, node, cfg_set);
+            }
+        }
+    }
+}
+
+/// Returns the scope of a suppression comment.
+/// A comment behind code suppresses the line up to the comment.
+/// Otherwise it suppresses the largest syntax node that starts with the code following the comment, e.g. an item or a statement.
+fn comment_scope(comment: &SyntaxToken) -> Option<TextRange> {
+    let mut previous = comment.prev_token();
+    while let Some(token) = previous.clone().filter(|token| token.kind().is_trivia()) {
+        if token.kind() == SyntaxKind::WHITESPACE && token.text().contains('\n') {
+            break;
+        }
+        previous = token.prev_token();
+    }
+    if previous
+        .as_ref()
+        .is_some_and(|token| !token.kind().is_trivia())
+    {
+        let mut line_start = TextSize::from(0);
+        let mut current = previous;
+        while let Some(token) = current {
+            if let Some(offset) = token.text().rfind('\n') {
+                line_start = token.text_range().start() + TextSize::from(offset as u32 + 1);
+                break;
+            }
+            current = token.prev_token();
+        }
+        return Some(TextRange::new(line_start, comment.text_range().start()));
+    }
+
+    let mut next = comment.next_token();
+    while let Some(token) = next.clone().filter(|token| token.kind().is_trivia()) {
+        next = token.next_token();
+    }
+    let next = next?;
+    let first_significant_token = |syntax_node: &SyntaxNode| {
+        syntax_node
+            .descendants_with_tokens()
+            .filter_map(|element| element.into_token())
+            .find(|token| !token.kind().is_trivia())
+    };
+    let mut scope = next.parent()?;
+    if first_significant_token(&scope).as_ref() != Some(&next) {
+        return Some(next.text_range());
+    }
+    while let Some(parent) = scope.parent().filter(|parent| {
+        parent.kind() != SyntaxKind::SOURCE_FILE
+            && first_significant_token(parent).as_ref() == Some(&next)
+    }) {
+        scope = parent;
+    }
+    Some(scope.text_range())
+}
+
+/// Parses a comment like `// archtest-allow(MayNotAccess, NoParentAccess): reason` into a suppression per rule.
+/// Doc comments and comments that do not start with the marker are ignored.
+fn parse_suppression_comment(
+    comment: &str,
+    text_range: TextRange,
+    scope: TextRange,
+) -> Vec<Suppression> {
+    let content = comment
+        .strip_prefix("//")
+        .or_else(|| comment.strip_prefix("/*"))
+        .unwrap_or_default();
+    if content.starts_with(['/', '!', '*']) {
+        return Vec::new();
+    }
+    let marker = match content.trim_start().strip_prefix("archtest-allow(") {
+        Some(marker) => marker,
+        None => return Vec::new(),
+    };
+    let end = match marker.find(')') {
+        Some(end) => end,
+        None => return Vec::new(),
+    };
+    let reason = marker[end + 1..]
+        .trim_end_matches("*/")
+        .trim()
+        .trim_start_matches(':')
+        .trim();
+    let reason = if reason.is_empty() {
+        None
+    } else {
+        Some(reason.to_owned())
+    };
+    split_arguments(&marker[..end])
+        .into_iter()
+        .map(|rule_name| Suppression::new(rule_name.to_owned(), reason.clone(), text_range, scope))
+        .collect()
+}
+
+/// Parses an attribute like `cfg_attr(archtest, allow(MayNotAccess, reason = "..."))` into a suppression per rule.
+fn parse_suppression_attribute(
+    attribute: &str,
+    text_range: TextRange,
+    scope: TextRange,
+) -> Vec<Suppression> {
+    let arguments = match attribute_arguments(attribute, "cfg_attr") {
+        Some(arguments) => split_arguments(arguments),
+        None => return Vec::new(),
+    };
+    if arguments.first() != Some(&"archtest") {
+        return Vec::new();
+    }
+    let mut suppressions = Vec::new();
+    for rules in arguments[1..]
+        .iter()
+        .filter_map(|argument| attribute_arguments(argument, "allow"))
+    {
+        let rules = split_arguments(rules);
+        let reason = rules.iter().find_map(|rule| {
+            let value = rule
+                .strip_prefix("reason")?
+                .trim_start()
+                .strip_prefix('=')?;
+            Some(value.trim().trim_matches('"').to_owned())
+        });
+        for rule_name in rules.iter().filter(|rule| !rule.starts_with("reason")) {
+            suppressions.push(Suppression::new(
+                (*rule_name).to_owned(),
+                reason.clone(),
+                text_range,
+                scope,
+            ));
+        }
+    }
+    suppressions
+}
+
+/// Reads the visibility of a `VISIBILITY` node, regardless of whitespace and comments inside of it,
+/// e.g. `pub(in crate::domain)` or the obsolete `crate`.
+fn parse_visibility(syntax_node: &SyntaxNode) -> Visibility {
+    let visibility = match ast::Visibility::cast(syntax_node.clone()) {
+        Some(visibility) => visibility,
+        None => return Visibility::Private,
+    };
+    if visibility.pub_token().is_none() {
+        return Visibility::Crate;
+    }
+    match visibility.kind() {
+        VisibilityKind::Pub => Visibility::Public,
+        VisibilityKind::PubCrate => Visibility::Crate,
+        VisibilityKind::PubSuper => Visibility::Super,
+        VisibilityKind::PubSelf => Visibility::Private,
+        VisibilityKind::In(path) => Visibility::InPath(
+            path.segments()
+                .map(|segment| segment.to_string())
+                .collect::<Vec<_>>()
+                .join("::"),
+        ),
+    }
+}
+
+fn parse_use_paths(
+    syntax_node: &SyntaxNode,
+) -> (Visibility, Vec<(String, TextRange, Option<String>)>) {
+    let mut visibility = Visibility::Private;
     let mut paths = Vec::new();
     for child in syntax_node.children() {
         match child.kind() {
@@ -596,11 +932,11 @@ fn parse_use_paths(syntax_node: &SyntaxNode) -> (bool, Vec<(String, TextRange)>)
                 continue;
             }
             SyntaxKind::VISIBILITY => {
-                visibility = true;
+                visibility = parse_visibility(&child);
             }
             SyntaxKind::USE_TREE => {
                 if child.to_string().ends_with('*') {
-                    paths.push((child.to_string(), child.text_range()));
+                    paths.push((child.to_string(), child.text_range(), None));
                 } else {
                     paths.append(&mut parse_use_tree(&child));
                 }
@@ -614,10 +950,11 @@ fn parse_use_paths(syntax_node: &SyntaxNode) -> (bool, Vec<(String, TextRange)>)
     (visibility, paths)
 }
 
-fn parse_use_tree(syntax_node: &SyntaxNode) -> Vec<(String, TextRange)> {
+fn parse_use_tree(syntax_node: &SyntaxNode) -> Vec<(String, TextRange, Option<String>)> {
     let mut path_segments = Vec::new();
     let mut current_prefix = String::new();
     let mut current_text_range = TextRange::empty(TextSize::default());
+    let mut current_alias = None;
     for sub_child in syntax_node.children() {
         match sub_child.kind() {
             SyntaxKind::PATH => {
@@ -625,15 +962,22 @@ fn parse_use_tree(syntax_node: &SyntaxNode) -> Vec<(String, TextRange)> {
                 current_text_range = sub_child.text_range();
             }
             SyntaxKind::RENAME => {
-                // TODO: Handle
-                continue;
+                // A rename to `_` only imports the methods of a trait and introduces no name
+                current_alias = Some(
+                    sub_child
+                        .children()
+                        .find(|child| child.kind() == SyntaxKind::NAME)
+                        .map(|name| name.to_string())
+                        .unwrap_or_else(|| "_".to_owned()),
+                );
             }
             SyntaxKind::USE_TREE_LIST => {
                 for use_tree in sub_child.children() {
-                    for (segment, _) in parse_use_tree(&use_tree) {
+                    for (segment, _, alias) in parse_use_tree(&use_tree) {
                         path_segments.push((
                             format!("{}::{}", current_prefix, segment),
                             sub_child.text_range(),
+                            alias,
                         ));
                     }
                 }
@@ -642,7 +986,7 @@ fn parse_use_tree(syntax_node: &SyntaxNode) -> Vec<(String, TextRange)> {
         }
     }
     if path_segments.is_empty() {
-        return vec![(current_prefix, current_text_range)];
+        return vec![(current_prefix, current_text_range, current_alias)];
     }
     path_segments
 }
@@ -669,11 +1013,7 @@ fn parse_path_type(syntax_node: &SyntaxNode) -> Vec<(String, TextRange)> {
                                             ));
                                         } else {
                                             obj_uses.push((
-                                                format!(
-                                                    "{}::{}",
-                                                    current_path,
-                                                    p_segment_child
-                                                ),
+                                                format!("{}::{}", current_path, p_segment_child),
                                                 p_segment_child.text_range(),
                                             ));
                                         }
@@ -800,3 +1140,70 @@ fn parse_assoc_func_item_list(syntax_node: &SyntaxNode) -> Vec<(String, TextRang
     }
     result
 }
+
+/// Returns the attributes of the `syntax_node` without the surrounding `#[...]`.
+/// If a `cfg_set` is specified, `cfg_attr` attributes are expanded to the attributes they enable.
+fn attributes(syntax_node: &SyntaxNode, cfg_set: Option<&CfgSet>) -> Vec<String> {
+    let mut result = Vec::new();
+    for attr in syntax_node
+        .children()
+        .filter(|child| child.kind() == SyntaxKind::ATTR)
+    {
+        expand_attribute(&attribute_content(&attr), cfg_set, &mut result);
+    }
+    result
+}
+
+/// Returns the content of an attribute without the surrounding `#[...]`, respectively `#![...]`.
+fn attribute_content(attr: &SyntaxNode) -> String {
+    attr.to_string()
+        .trim_start_matches('#')
+        .trim_start_matches('!')
+        .trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .trim()
+        .to_owned()
+}
+
+fn expand_attribute(attribute: &str, cfg_set: Option<&CfgSet>, result: &mut Vec<String>) {
+    if let (Some(cfg_set), Some(arguments)) = (cfg_set, attribute_arguments(attribute, "cfg_attr"))
+    {
+        let arguments = split_arguments(arguments);
+        if let Some(predicate) = arguments
+            .first()
+            .and_then(|predicate| CfgPredicate::parse(predicate))
+        {
+            if predicate.evaluate(cfg_set) {
+                for argument in arguments[1..].iter() {
+                    expand_attribute(argument, Some(cfg_set), result);
+                }
+            }
+        }
+        return;
+    }
+    result.push(attribute.to_owned());
+}
+
+fn attribute_arguments<'a>(attribute: &'a str, name: &str) -> Option<&'a str> {
+    attribute
+        .strip_prefix(name)?
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')
+}
+
+/// Returns `false` if the `syntax_node` has a `cfg` attribute that is not satisfied by the `cfg_set`.
+/// Without a `cfg_set` every node is enabled. Malformed predicates are considered to be satisfied.
+fn is_enabled(syntax_node: &SyntaxNode, cfg_set: Option<&CfgSet>) -> bool {
+    let cfg_set = match cfg_set {
+        Some(cfg_set) => cfg_set,
+        None => return true,
+    };
+    attributes(syntax_node, Some(cfg_set))
+        .iter()
+        .filter_map(|attribute| attribute_arguments(attribute, "cfg"))
+        .all(|predicate| {
+            CfgPredicate::parse(predicate).is_none_or(|predicate| predicate.evaluate(cfg_set))
+        })
+}
@@ -0,0 +1,46 @@
+use crate::parser::domain_values::{CfgPredicate, CfgSet};
+
+#[test]
+fn parse() {
+    assert_eq!(
+        CfgPredicate::parse("test"),
+        Some(CfgPredicate::Option("test".to_owned()))
+    );
+    assert_eq!(
+        CfgPredicate::parse("feature = \"serde\""),
+        Some(CfgPredicate::KeyValue(
+            "feature".to_owned(),
+            "serde".to_owned()
+        ))
+    );
+    assert_eq!(
+        CfgPredicate::parse("all(unix, not(test), feature = \"a,b\")"),
+        Some(CfgPredicate::All(vec![
+            CfgPredicate::Option("unix".to_owned()),
+            CfgPredicate::Not(Box::new(CfgPredicate::Option("test".to_owned()))),
+            CfgPredicate::KeyValue("feature".to_owned(), "a,b".to_owned()),
+        ]))
+    );
+    assert_eq!(CfgPredicate::parse("not(unix, test)"), None);
+    assert_eq!(CfgPredicate::parse("unknown(unix)"), None);
+}
+
+#[test]
+fn evaluate() {
+    let cfg_set = CfgSet::new()
+        .with_test()
+        .with_feature("serde".to_owned())
+        .with_key_value("target_os".to_owned(), "linux".to_owned());
+
+    let evaluate = |predicate: &str| CfgPredicate::parse(predicate).unwrap().evaluate(&cfg_set);
+    assert!(evaluate("test"));
+    assert!(!evaluate("unix"));
+    assert!(evaluate("feature = \"serde\""));
+    assert!(!evaluate("feature = \"postgres\""));
+    assert!(evaluate("all(test, target_os = \"linux\")"));
+    assert!(!evaluate("all(test, windows)"));
+    assert!(evaluate("any(windows, feature = \"serde\")"));
+    assert!(evaluate("not(windows)"));
+    assert!(evaluate("all()"));
+    assert!(!evaluate("any()"));
+}
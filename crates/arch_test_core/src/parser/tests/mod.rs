@@ -1,3 +1,5 @@
+mod cfg_predicate;
 mod module_node;
 mod module_tree;
 mod parser;
+mod visibility;
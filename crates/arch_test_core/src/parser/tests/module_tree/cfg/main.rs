@@ -0,0 +1,13 @@
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(not(feature = "postgres"))]
+mod sqlite;
+#[cfg_attr(test, path = "mocks.rs")]
+mod support;
+#[cfg(test)]
+mod tests;
+
+#[cfg(any(windows, test))]
+fn windows_or_test() {}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use std::fmt::Debug;
+
+pub struct Order;
+
+impl Order {
+    pub fn new() -> Self {
+        Order
+    }
+}
+
+fn print(order: &dyn Debug) {}
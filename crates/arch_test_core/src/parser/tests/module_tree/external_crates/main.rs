@@ -0,0 +1,11 @@
+use diesel::Connection;
+
+mod domain;
+
+struct Database;
+
+impl Connection for Database {}
+
+fn main() {
+    tokio::spawn(domain::Order::new);
+}
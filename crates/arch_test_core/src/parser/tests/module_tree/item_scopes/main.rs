@@ -0,0 +1,11 @@
+mod model;
+
+use crate::model::Order;
+
+impl Order {
+    fn total(&self) -> u32 {
+        0
+    }
+}
+
+impl Default for Wrapper<Order> {}
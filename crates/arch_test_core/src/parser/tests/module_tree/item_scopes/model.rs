@@ -0,0 +1,17 @@
+pub struct Order {
+    id: u32,
+}
+
+impl Priced for Order {}
+
+pub enum Status {
+    Open,
+}
+
+pub trait Priced {}
+
+pub type Orders = Vec<Order>;
+
+fn helper() {
+    let add = |x: u32| x;
+}
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::parser::domain_values::{ObjectType, ObjectUse};
+use crate::parser::domain_values::{CfgSet, ObjectType, ObjectUse};
 use crate::parser::entities::ModuleNode;
 use crate::ModuleTree;
 
@@ -130,3 +130,263 @@ fn filter_unused_uses() {
         "crate::file_1::Test1".to_owned()
     );
 }
+
+#[test]
+fn external_crates() {
+    let module_tree = ModuleTree::new("src/parser/tests/module_tree/external_crates/main.rs");
+
+    let tree = module_tree.tree();
+    assert_eq!(tree.len(), 4);
+    let external_crates: Vec<&String> = tree
+        .iter()
+        .filter(|node| node.is_external())
+        .map(|node| node.module_name())
+        .collect();
+    assert_eq!(external_crates.len(), 2);
+    assert!(external_crates.contains(&&"diesel".to_owned()));
+    assert!(external_crates.contains(&&"tokio".to_owned()));
+
+    let use_map = module_tree.possible_uses();
+    let connection = use_map.get("diesel::Connection").unwrap();
+    assert_eq!(tree[connection.node_index()].module_name(), "diesel");
+    assert_eq!(
+        connection.usable_object().object_type(),
+        ObjectType::External
+    );
+    assert!(use_map.contains_key("tokio::spawn"));
+    assert!(!use_map.contains_key("std::fmt::Debug"));
+    assert!(!use_map.contains_key("Order::new"));
+
+    let used_crates: Vec<&String> = tree[0]
+        .use_relations(tree, use_map, false)
+        .into_iter()
+        .filter(|use_relation| tree[use_relation.used_object().node_index()].is_external())
+        .map(|use_relation| tree[use_relation.used_object().node_index()].module_name())
+        .collect();
+    assert_eq!(used_crates.len(), 2);
+    assert!(used_crates.contains(&&"diesel".to_owned()));
+    assert!(used_crates.contains(&&"tokio".to_owned()));
+}
+
+#[test]
+fn std_only() {
+    let module_tree = ModuleTree::new("src/parser/tests/module_tree/std_only/main.rs");
+    let tree = module_tree.tree();
+    assert_eq!(tree.len(), 2);
+    assert!(tree.iter().all(|node| !node.is_external()));
+}
+
+#[test]
+fn cfg() {
+    let module_names = |module_tree: &ModuleTree| -> Vec<String> {
+        module_tree
+            .tree()
+            .iter()
+            .map(|node| node.module_name().clone())
+            .collect()
+    };
+    let defines_function = |module_tree: &ModuleTree| {
+        module_tree.tree()[0]
+            .usable_objects
+            .iter()
+            .any(|obj| obj.object_name == "windows_or_test")
+    };
+
+    let module_tree = ModuleTree::new("src/parser/tests/module_tree/cfg/main.rs");
+    assert_eq!(
+        module_names(&module_tree),
+        vec!["crate", "postgres", "sqlite", "support", "tests"]
+    );
+    assert!(module_tree.tree()[3].file_path().ends_with("/support.rs"));
+    assert!(defines_function(&module_tree));
+
+    let module_tree = ModuleTree::new_with_cfg(
+        "src/parser/tests/module_tree/cfg/main.rs",
+        &CfgSet::new().with_feature("postgres".to_owned()),
+    );
+    assert_eq!(
+        module_names(&module_tree),
+        vec!["crate", "postgres", "support"]
+    );
+    assert!(module_tree.tree()[2].file_path().ends_with("/support.rs"));
+    assert!(!defines_function(&module_tree));
+
+    let module_tree = ModuleTree::new_with_cfg(
+        "src/parser/tests/module_tree/cfg/main.rs",
+        &CfgSet::new().with_test(),
+    );
+    assert_eq!(
+        module_names(&module_tree),
+        vec!["crate", "sqlite", "support", "tests"]
+    );
+    assert!(module_tree.tree()[2].file_path().ends_with("/mocks.rs"));
+    assert!(defines_function(&module_tree));
+}
+
+#[test]
+fn rename() {
+    let module_tree = ModuleTree::new("src/parser/tests/module_tree/rename/main.rs");
+    let tree = module_tree.tree();
+    let used_modules = |node_index: usize| -> Vec<String> {
+        tree[node_index]
+            .use_relations(tree, module_tree.possible_uses(), false)
+            .into_iter()
+            .map(|use_relation| {
+                tree[use_relation.used_object().node_index()].get_fully_qualified_path(tree)
+            })
+            .collect()
+    };
+
+    assert!(tree[0]
+        .usable_objects
+        .iter()
+        .any(|obj| obj.object_type() == ObjectType::ImplicitUse
+            && obj.object_name == "crate::db::Repository::new"));
+    assert_eq!(used_modules(0), vec!["crate::db"]);
+
+    assert_eq!(tree[1].module_name(), "api");
+    assert!(tree[1]
+        .usable_objects
+        .iter()
+        .any(|obj| obj.object_name == "crate::db::models::Order"));
+    assert_eq!(used_modules(1), vec!["crate::db::models"]);
+}
+
+#[test]
+fn path_resolution() {
+    let module_tree = ModuleTree::new("src/parser/tests/module_tree/path_resolution/main.rs");
+    let tree = module_tree.tree();
+    let pricing = tree
+        .iter()
+        .find(|node| node.module_name() == "pricing")
+        .unwrap();
+    let object_names: Vec<&String> = pricing
+        .usable_objects
+        .iter()
+        .filter(|obj| obj.object_type() == ObjectType::Use)
+        .map(|obj| obj.object_name())
+        .collect();
+    assert_eq!(
+        object_names,
+        vec![
+            "crate::domain::services::pricing::rules::Rule",
+            "crate::domain::model::Customer",
+            "crate::infrastructure::Database",
+            "serde::Serialize",
+            "crate::infrastructure::Cache",
+            "crate::domain::model::Order",
+        ]
+    );
+
+    let mut used_modules: Vec<String> = pricing
+        .use_relations(tree, module_tree.possible_uses(), false)
+        .into_iter()
+        .map(|use_relation| {
+            tree[use_relation.used_object().node_index()].get_fully_qualified_path(tree)
+        })
+        .collect();
+    used_modules.sort();
+    used_modules.dedup();
+    assert_eq!(
+        used_modules,
+        vec![
+            "crate::domain::model",
+            "crate::domain::services",
+            "crate::domain::services::pricing::rules",
+            "crate::infrastructure",
+            "serde",
+        ]
+    );
+}
+
+#[test]
+fn resolve_path() {
+    let module_tree = ModuleTree::new("src/parser/tests/module_tree/path_resolution/main.rs");
+    let pricing = module_tree
+        .tree()
+        .iter()
+        .position(|node| node.module_name() == "pricing")
+        .unwrap();
+
+    assert_eq!(
+        module_tree.resolve_path(pricing, "super::super::model", false),
+        "crate::domain::model"
+    );
+    assert_eq!(
+        module_tree.resolve_path(pricing, "self::super::total", false),
+        "crate::domain::services::total"
+    );
+    assert_eq!(
+        module_tree.resolve_path(pricing, "crate::infrastructure::Cache", false),
+        "crate::infrastructure::Cache"
+    );
+    assert_eq!(
+        module_tree.resolve_path(pricing, "rules::Rule", false),
+        "crate::domain::services::pricing::rules::Rule"
+    );
+    assert_eq!(
+        module_tree.resolve_path(pricing, "::serialization::de", false),
+        "serde::de"
+    );
+    assert_eq!(
+        module_tree.resolve_path(pricing, "this_crate::domain", false),
+        "crate::domain"
+    );
+    assert_eq!(
+        module_tree.resolve_path(pricing, "infrastructure::Cache", true),
+        "crate::infrastructure::Cache"
+    );
+    assert_eq!(
+        module_tree.resolve_path(pricing, "infrastructure::Cache", false),
+        "infrastructure::Cache"
+    );
+    assert_eq!(
+        module_tree.resolve_path(pricing, "tokio::spawn", true),
+        "tokio::spawn"
+    );
+}
+
+#[test]
+fn item_scopes() {
+    let module_tree = ModuleTree::new("src/parser/tests/module_tree/item_scopes/main.rs");
+    let tree = module_tree.tree();
+    let item_scopes = |node_index: usize| -> Vec<(String, Option<ObjectType>)> {
+        tree[node_index]
+            .item_scopes()
+            .iter()
+            .map(|item_scope| (item_scope.name().clone(), item_scope.object_type()))
+            .collect()
+    };
+
+    assert_eq!(
+        item_scopes(0),
+        vec![
+            ("Order".to_owned(), Some(ObjectType::Struct)),
+            ("Wrapper".to_owned(), None)
+        ]
+    );
+    assert_eq!(
+        item_scopes(1),
+        vec![
+            ("Order".to_owned(), Some(ObjectType::Struct)),
+            ("Order".to_owned(), Some(ObjectType::Struct)),
+            ("Status".to_owned(), Some(ObjectType::Enum)),
+            ("Priced".to_owned(), Some(ObjectType::Trait)),
+            ("Orders".to_owned(), Some(ObjectType::TypeAlias)),
+            ("helper".to_owned(), Some(ObjectType::Function))
+        ]
+    );
+
+    let order_use = tree[0]
+        .usable_objects
+        .iter()
+        .find(|obj| obj.object_type() == ObjectType::ImplicitUse)
+        .unwrap();
+    assert_eq!(
+        tree[0]
+            .item_scopes_containing(order_use.text_range())
+            .map(|item_scope| item_scope.name().as_str())
+            .collect::<Vec<&str>>(),
+        vec!["Order"]
+    );
+}
@@ -0,0 +1,5 @@
+use super::model::Order;
+
+mod pricing;
+
+pub fn total(order: Order) {}
@@ -0,0 +1,16 @@
+use self::rules::Rule;
+use super::super::model::Customer;
+use ::infrastructure::Database;
+use ::serialization::Serialize;
+use infrastructure::Cache;
+use this_crate::domain::model::Order;
+
+mod rules {
+    pub struct Rule;
+}
+
+fn discount(customer: Customer, database: Database, cache: Cache, rule: Rule, order: Order) {
+    super::total(order);
+}
+
+fn serialize(value: &dyn Serialize) {}
@@ -0,0 +1,7 @@
+extern crate self as this_crate;
+extern crate serde as serialization;
+
+mod domain;
+mod infrastructure;
+
+fn main() {}
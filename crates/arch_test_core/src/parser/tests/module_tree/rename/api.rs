@@ -0,0 +1,3 @@
+use crate::db::Entity;
+
+fn handle(entity: Entity) {}
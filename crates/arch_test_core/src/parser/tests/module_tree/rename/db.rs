@@ -0,0 +1,11 @@
+pub use self::models::Order as Entity;
+
+mod models;
+
+pub struct Repository;
+
+impl Repository {
+    pub fn new() -> Self {
+        Repository
+    }
+}
@@ -0,0 +1,8 @@
+use crate::db::Repository as Repo;
+
+mod api;
+mod db;
+
+fn main() {
+    let repository = Repo::new();
+}
@@ -0,0 +1,6 @@
+use std::fmt::Debug;
+
+#[derive(Debug)]
+pub struct Order;
+
+fn print(order: &dyn Debug) {}
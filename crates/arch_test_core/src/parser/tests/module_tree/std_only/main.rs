@@ -0,0 +1,10 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+mod domain;
+
+fn main() {
+    let orders: HashMap<u32, domain::Order> = HashMap::new();
+    core::mem::drop(orders);
+    std::process::exit(0);
+}
@@ -5,7 +5,7 @@ use std::path::Path;
 fn record() {
     let mut node_tree = Vec::new();
     let path = Path::new("src/parser/tests/parser/expressions/record.rs");
-    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned());
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
 
     // We actively dont parse the record expression
 }
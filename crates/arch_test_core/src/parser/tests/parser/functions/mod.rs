@@ -7,7 +7,7 @@ use crate::parser::services::parse_main_or_mod_file_into_tree;
 fn return_type() {
     let mut node_tree = Vec::new();
     let path = Path::new("src/parser/tests/parser/functions/return_type.rs");
-    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned());
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
 
     assert_eq!(
         node_tree[0].usable_objects[0].object_type(),
@@ -38,7 +38,7 @@ fn return_type() {
 fn param_list() {
     let mut node_tree = Vec::new();
     let path = Path::new("src/parser/tests/parser/functions/param_list.rs");
-    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned());
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
 
     assert_eq!(
         node_tree[0].usable_objects[0].object_type(),
@@ -6,7 +6,7 @@ use crate::parser::services::parse_main_or_mod_file_into_tree;
 fn simple() {
     let mut node_tree = Vec::new();
     let path = Path::new("src/parser/tests/parser/macros/simple.rs");
-    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned());
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
 
     assert_eq!(node_tree[0].usable_objects.len(), 2);
     assert_eq!(
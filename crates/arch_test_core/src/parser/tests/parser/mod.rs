@@ -4,6 +4,8 @@ mod functions;
 mod macros;
 mod modules;
 mod struct_stmt;
+mod suppressions;
 mod traits;
 mod types;
+mod unsafe_code;
 mod use_stmt;
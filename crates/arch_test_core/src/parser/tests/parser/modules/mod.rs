@@ -6,7 +6,7 @@ use std::path::Path;
 fn inner_modules() {
     let mut node_tree = Vec::new();
     let path = Path::new("src/parser/tests/parser/modules/inner_modules.rs");
-    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned());
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
 
     assert_eq!(node_tree.len(), 4);
     assert_eq!(node_tree[0].level(), 0);
@@ -82,7 +82,7 @@ fn inner_modules() {
 fn nested_folders_and_files() {
     let mut node_tree = Vec::new();
     let path = Path::new("src/parser/tests/parser/modules/nested_folders_and_files/root.rs");
-    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned());
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
 
     assert_eq!(node_tree.len(), 10);
     assert_eq!(node_tree[0].level(), 0);
@@ -135,7 +135,7 @@ fn nested_folders_and_files() {
 fn path_attribute() {
     let mut node_tree = Vec::new();
     let path = Path::new("src/parser/tests/parser/modules/path_attribute/main.rs");
-    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "crate".to_owned());
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "crate".to_owned(), None);
 
     assert_eq!(node_tree.len(), 2);
     assert_eq!(
@@ -151,7 +151,7 @@ fn path_attribute() {
 fn sub_module_without_mod() {
     let mut node_tree = Vec::new();
     let path = Path::new("src/parser/tests/parser/modules/sub_module_without_mod/main.rs");
-    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "crate".to_owned());
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "crate".to_owned(), None);
 
     assert_eq!(node_tree.len(), 3);
     assert_eq!(node_tree[0].module_name(), "crate");
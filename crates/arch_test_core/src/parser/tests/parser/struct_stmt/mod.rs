@@ -1,13 +1,13 @@
 use std::path::Path;
 
-use crate::parser::domain_values::ObjectType;
+use crate::parser::domain_values::{ObjectType, Visibility};
 use crate::parser::services::parse_main_or_mod_file_into_tree;
 
 #[test]
 fn empty() {
     let mut node_tree = Vec::new();
     let path = Path::new("src/parser/tests/parser/struct_stmt/empty.rs");
-    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned());
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
 
     assert_eq!(node_tree.len(), 1);
     assert_eq!(node_tree[0].usable_objects.len(), 2);
@@ -33,7 +33,7 @@ fn empty() {
 fn complex() {
     let mut node_tree = Vec::new();
     let path = Path::new("src/parser/tests/parser/struct_stmt/complex.rs");
-    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned());
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
 
     assert_eq!(
         node_tree[0].usable_objects[0].object_type(),
@@ -69,3 +69,33 @@ fn complex() {
         );
     }
 }
+
+#[test]
+fn visibility() {
+    let mut node_tree = Vec::new();
+    let path = Path::new("src/parser/tests/parser/struct_stmt/visibility.rs");
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
+
+    let visibilities: Vec<&Visibility> = node_tree[0]
+        .usable_objects
+        .iter()
+        .map(|obj| obj.visibility())
+        .collect();
+    assert_eq!(
+        visibilities,
+        vec![
+            &Visibility::Public,
+            &Visibility::Crate,
+            &Visibility::Super,
+            &Visibility::InPath("crate::domain".to_owned()),
+            &Visibility::Private,
+            &Visibility::Private,
+            &Visibility::Crate,
+            &Visibility::Super,
+            &Visibility::InPath("crate::domain".to_owned()),
+            &Visibility::InPath("super::super".to_owned())
+        ]
+    );
+    assert!(node_tree[0].usable_objects[1].is_public());
+    assert!(!node_tree[0].usable_objects[4].is_public());
+}
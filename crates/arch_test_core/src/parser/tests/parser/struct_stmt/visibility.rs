@@ -0,0 +1,11 @@
+pub struct Public;
+pub(crate) struct Crate;
+pub(super) struct Super;
+pub(in crate::domain) struct InPath;
+pub(self) struct SelfRestricted;
+struct Private;
+
+pub ( crate ) struct SpacedCrate;
+pub(/* only here */ super) struct CommentedSuper;
+pub(in crate :: domain) struct SpacedInPath;
+pub(in super::super) struct InSuperPath;
@@ -0,0 +1,19 @@
+#![cfg_attr(archtest, allow(NoParentAccess, reason = "legacy module"))]
+
+// archtest-allow(MayNotAccess): the repository is wired up here
+use crate::infrastructure::Repository;
+
+use crate::infrastructure::Connection; // archtest-allow(MayNotAccess, MayOnlyAccess)
+
+#[cfg_attr(archtest, allow(NamingConvention))]
+struct handler;
+
+fn main() {
+    // archtest-allow(ForbiddenPaths)
+    let file = std::fs::read("data");
+    let exit = std::process::exit;
+}
+
+// An ordinary comment
+/// archtest-allow(MayNotAccess) in a doc comment is no suppression
+fn unrelated() {}
@@ -0,0 +1,52 @@
+use std::path::Path;
+
+use crate::parser::services::parse_main_or_mod_file_into_tree;
+
+fn text<'a>(content: &'a str, text_range: &ra_ap_syntax::TextRange) -> &'a str {
+    &content[usize::from(text_range.start())..usize::from(text_range.end())]
+}
+
+#[test]
+fn markers() {
+    let mut node_tree = Vec::new();
+    let path = Path::new("src/parser/tests/parser/suppressions/markers.rs");
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
+    let content = std::fs::read_to_string(path).unwrap();
+
+    let suppressions = node_tree[0].suppressions();
+    let rule_names: Vec<&str> = suppressions
+        .iter()
+        .map(|suppression| suppression.rule_name().as_str())
+        .collect();
+    assert_eq!(
+        rule_names,
+        vec![
+            "NoParentAccess",
+            "MayNotAccess",
+            "MayNotAccess",
+            "MayOnlyAccess",
+            "NamingConvention",
+            "ForbiddenPaths"
+        ]
+    );
+
+    assert_eq!(suppressions[0].reason().unwrap(), "legacy module");
+    assert_eq!(text(&content, suppressions[0].scope()), content);
+    assert_eq!(
+        suppressions[1].reason().unwrap(),
+        "the repository is wired up here"
+    );
+    assert!(
+        text(&content, suppressions[1].scope()).ends_with("use crate::infrastructure::Repository;")
+    );
+    assert_eq!(
+        text(&content, suppressions[2].scope()).trim(),
+        "use crate::infrastructure::Connection;"
+    );
+    assert!(suppressions[3].reason().is_none());
+    assert!(text(&content, suppressions[4].scope()).ends_with("struct handler;"));
+    assert_eq!(
+        text(&content, suppressions[5].scope()),
+        "let file = std::fs::read(\"data\");"
+    );
+}
@@ -6,7 +6,7 @@ use std::path::Path;
 fn functions() {
     let mut node_tree = Vec::new();
     let path = Path::new("src/parser/tests/parser/traits/functions.rs");
-    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned());
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
 
     assert_eq!(
         node_tree[0].usable_objects[0].object_type(),
@@ -33,7 +33,7 @@ fn functions() {
 fn impl_normal() {
     let mut node_tree = Vec::new();
     let path = Path::new("src/parser/tests/parser/traits/impl_normal.rs");
-    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned());
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
 
     for i in 0..3 {
         assert_eq!(
@@ -50,7 +50,7 @@ fn impl_normal() {
 fn impl_trait() {
     let mut node_tree = Vec::new();
     let path = Path::new("src/parser/tests/parser/traits/impl_trait.rs");
-    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned());
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
     for i in 0..4 {
         assert_eq!(
             node_tree[0].usable_objects[i].object_type(),
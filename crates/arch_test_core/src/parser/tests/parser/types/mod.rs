@@ -7,7 +7,7 @@ use crate::parser::services::parse_main_or_mod_file_into_tree;
 fn complex_tuple() {
     let mut node_tree = Vec::new();
     let path = Path::new("src/parser/tests/parser/types/complex_tuple.rs");
-    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned());
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
 
     assert_eq!(
         node_tree[0].usable_objects[0].object_type(),
@@ -36,7 +36,7 @@ fn complex_tuple() {
 fn complex_generic() {
     let mut node_tree = Vec::new();
     let path = Path::new("src/parser/tests/parser/types/complex_generic.rs");
-    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned());
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
 
     assert_eq!(
         node_tree[0].usable_objects[0].object_type(),
@@ -74,7 +74,7 @@ fn complex_generic() {
 fn alias() {
     let mut node_tree = Vec::new();
     let path = Path::new("src/parser/tests/parser/types/alias.rs");
-    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned());
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
 
     assert_eq!(node_tree[0].usable_objects.len(), 2);
     assert_eq!(
@@ -0,0 +1,66 @@
+use std::path::Path;
+
+use crate::parser::domain_values::{CfgSet, UnsafeKind};
+use crate::parser::services::parse_main_or_mod_file_into_tree;
+
+#[test]
+fn sites() {
+    let mut node_tree = Vec::new();
+    let path = Path::new("src/parser/tests/parser/unsafe_code/sites.rs");
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
+
+    let kinds: Vec<UnsafeKind> = node_tree[0]
+        .unsafe_sites()
+        .iter()
+        .map(|unsafe_site| unsafe_site.kind())
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            UnsafeKind::ExternBlock,
+            UnsafeKind::Trait,
+            UnsafeKind::Impl,
+            UnsafeKind::Function,
+            UnsafeKind::Function,
+            UnsafeKind::Block,
+            UnsafeKind::Block,
+            UnsafeKind::Block
+        ]
+    );
+    let text_range = node_tree[0].unsafe_sites()[3].text_range();
+    assert_eq!(
+        &std::fs::read_to_string(path).unwrap()
+            [usize::from(text_range.start())..usize::from(text_range.end())],
+        "unsafe"
+    );
+}
+
+#[test]
+fn sites_with_cfg() {
+    let mut node_tree = Vec::new();
+    let path = Path::new("src/parser/tests/parser/unsafe_code/sites.rs");
+    parse_main_or_mod_file_into_tree(
+        &mut node_tree,
+        path,
+        0,
+        None,
+        "WAMBO".to_owned(),
+        Some(&CfgSet::new()),
+    );
+
+    assert_eq!(node_tree[0].unsafe_sites().len(), 7);
+}
+
+#[test]
+fn record_expr() {
+    let mut node_tree = Vec::new();
+    let path = Path::new("src/parser/tests/parser/unsafe_code/record_expr.rs");
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
+
+    let kinds: Vec<UnsafeKind> = node_tree[0]
+        .unsafe_sites()
+        .iter()
+        .map(|unsafe_site| unsafe_site.kind())
+        .collect();
+    assert_eq!(kinds, vec![UnsafeKind::Block, UnsafeKind::Block]);
+}
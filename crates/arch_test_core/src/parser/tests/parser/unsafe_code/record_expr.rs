@@ -0,0 +1,16 @@
+struct Wrapper {
+    value: u32,
+}
+
+fn read() -> u32 {
+    0
+}
+
+fn wrap(base: Wrapper) -> Wrapper {
+    let _ = Wrapper {
+        value: unsafe { read() },
+    };
+    Wrapper {
+        ..unsafe { std::ptr::read(&base) }
+    }
+}
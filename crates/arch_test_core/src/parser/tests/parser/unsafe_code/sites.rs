@@ -0,0 +1,33 @@
+extern "C" {
+    fn abs(input: i32) -> i32;
+}
+
+unsafe trait Zeroable {}
+
+unsafe impl Zeroable for u32 {}
+
+pub unsafe fn read(pointer: *const u32) -> u32 {
+    *pointer
+}
+
+struct Buffer;
+
+impl Buffer {
+    unsafe fn get_unchecked(&self) -> u32 {
+        0
+    }
+
+    fn get(&self) -> u32 {
+        unsafe { self.get_unchecked() }
+    }
+
+    #[cfg(feature = "nightly")]
+    fn get_nightly(&self) -> u32 {
+        unsafe { self.get_unchecked() }
+    }
+}
+
+fn main() {
+    let value = unsafe { abs(-3) };
+    let safe = { 3 };
+}
@@ -0,0 +1,2 @@
+extern crate serde;
+pub extern crate diesel as db;
@@ -7,7 +7,7 @@ use crate::parser::services::parse_main_or_mod_file_into_tree;
 fn visibility() {
     let mut node_tree = Vec::new();
     let path = Path::new("src/parser/tests/parser/use_stmt/visibility.rs");
-    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned());
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
 
     assert_eq!(node_tree.len(), 1);
     assert_eq!(node_tree[0].usable_objects.len(), 2);
@@ -33,7 +33,7 @@ fn visibility() {
 fn nested() {
     let mut node_tree = Vec::new();
     let path = Path::new("src/parser/tests/parser/use_stmt/nested.rs");
-    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned());
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
 
     let usable_objects = &node_tree[0].usable_objects;
     for obj in usable_objects {
@@ -59,7 +59,45 @@ fn nested() {
 fn single_stmt() {
     let mut node_tree = Vec::new();
     let path = Path::new("src/parser/tests/parser/use_stmt/single_stmt.rs");
-    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned());
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
 
     assert_eq!(node_tree[0].usable_objects[0].object_name, "a".to_owned());
 }
+
+#[test]
+fn rename() {
+    let mut node_tree = Vec::new();
+    let path = Path::new("src/parser/tests/parser/use_stmt/rename.rs");
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
+
+    let usable_objects = &node_tree[0].usable_objects;
+    assert_eq!(usable_objects.len(), 4);
+    assert_eq!(usable_objects[0].object_type(), ObjectType::Use);
+    assert_eq!(usable_objects[0].object_name, "a::b".to_owned());
+    assert_eq!(usable_objects[0].alias(), Some(&"c".to_owned()));
+    assert_eq!(usable_objects[1].object_type(), ObjectType::RePublish);
+    assert_eq!(usable_objects[1].object_name, "d::e".to_owned());
+    assert_eq!(usable_objects[1].alias(), Some(&"f".to_owned()));
+    assert_eq!(usable_objects[2].object_name, "d::g::h".to_owned());
+    assert_eq!(usable_objects[2].alias(), None);
+    assert_eq!(usable_objects[3].object_name, "d::i".to_owned());
+    assert_eq!(usable_objects[3].alias(), Some(&"_".to_owned()));
+}
+
+#[test]
+fn extern_crate() {
+    let mut node_tree = Vec::new();
+    let path = Path::new("src/parser/tests/parser/use_stmt/extern_crate.rs");
+    parse_main_or_mod_file_into_tree(&mut node_tree, path, 0, None, "WAMBO".to_owned(), None);
+
+    let usable_objects = &node_tree[0].usable_objects;
+    assert_eq!(usable_objects.len(), 2);
+    assert_eq!(usable_objects[0].object_type(), ObjectType::ExternCrate);
+    assert_eq!(usable_objects[0].object_name, "serde".to_owned());
+    assert!(!usable_objects[0].is_public());
+    assert_eq!(usable_objects[0].alias(), None);
+    assert_eq!(usable_objects[1].object_type(), ObjectType::ExternCrate);
+    assert_eq!(usable_objects[1].object_name, "diesel".to_owned());
+    assert!(usable_objects[1].is_public());
+    assert_eq!(usable_objects[1].alias(), Some(&"db".to_owned()));
+}
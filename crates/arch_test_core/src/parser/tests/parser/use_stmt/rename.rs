@@ -0,0 +1,2 @@
+use a::b as c;
+pub use d::{e as f, g::h, i as _};
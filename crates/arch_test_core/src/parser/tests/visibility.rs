@@ -0,0 +1,26 @@
+use crate::parser::domain_values::Visibility;
+
+#[test]
+fn parse() {
+    assert_eq!(Visibility::parse("pub"), Some(Visibility::Public));
+    assert_eq!(Visibility::parse("pub(crate)"), Some(Visibility::Crate));
+    assert_eq!(Visibility::parse("pub (in crate)"), Some(Visibility::Crate));
+    assert_eq!(Visibility::parse("crate"), Some(Visibility::Crate));
+    assert_eq!(Visibility::parse("pub(super)"), Some(Visibility::Super));
+    assert_eq!(Visibility::parse("pub(self)"), Some(Visibility::Private));
+    assert_eq!(
+        Visibility::parse("pub(in crate::domain)"),
+        Some(Visibility::InPath("crate::domain".to_owned()))
+    );
+    assert_eq!(Visibility::parse("protected"), None);
+    assert_eq!(Visibility::parse("pub(crate"), None);
+    assert_eq!(Visibility::parse("pub(domain)"), None);
+}
+
+#[test]
+fn rank() {
+    assert!(Visibility::Private.rank() < Visibility::InPath("crate::domain".to_owned()).rank());
+    assert!(Visibility::InPath("crate::domain".to_owned()).rank() < Visibility::Super.rank());
+    assert!(Visibility::Super.rank() < Visibility::Crate.rank());
+    assert!(Visibility::Crate.rank() < Visibility::Public.rank());
+}
@@ -1,3 +1,5 @@
 pub use self::read_file_content::read_file_content;
+pub use self::split_arguments::split_arguments;
 
 mod read_file_content;
+mod split_arguments;
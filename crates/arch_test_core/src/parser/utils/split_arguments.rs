@@ -0,0 +1,24 @@
+/// Splits the arguments of an attribute, e.g. `unix, feature = "a,b", not(test)`, at the commas that are not nested.
+pub fn split_arguments(arguments: &str) -> Vec<&str> {
+    let mut result = Vec::new();
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut start = 0;
+    let mut previous = ' ';
+    for (index, character) in arguments.char_indices() {
+        match character {
+            '"' if previous != '\\' => in_string = !in_string,
+            '(' | '[' if !in_string => depth += 1,
+            ')' | ']' if !in_string => depth -= 1,
+            ',' if !in_string && depth == 0 => {
+                result.push(arguments[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+        previous = character;
+    }
+    result.push(arguments[start..].trim());
+    result.retain(|argument| !argument.is_empty());
+    result
+}
@@ -1,8 +1,14 @@
+use crate::domain_values::{ItemKind, ItemSpecification};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum AccessRule {
     NoParentAccess,
     NoModuleCyclicDependencies,
     NoLayerCyclicDependencies,
+    NoNamedLayerCyclicDependencies {
+        #[serde(default)]
+        layers: Vec<String>,
+    },
     MayOnlyAccess {
         accessor: String,
         accessed: Vec<String>,
@@ -23,4 +29,42 @@ pub enum AccessRule {
         accessed: String,
         when_same_parent: bool,
     },
+    LayeredArchitecture {
+        layers: Vec<String>,
+        #[serde(default)]
+        strict: bool,
+    },
+    OnionArchitecture {
+        domain_model: Vec<String>,
+        domain_services: Vec<String>,
+        application_services: Vec<String>,
+        adapters: Vec<String>,
+    },
+    ItemMayNotAccess {
+        accessor: ItemSpecification,
+        accessed: Vec<ItemSpecification>,
+    },
+    ItemMayOnlyBeAccessedBy {
+        accessed: ItemSpecification,
+        accessors: Vec<ItemSpecification>,
+    },
+    NamingConvention {
+        layer: String,
+        object_type: ItemKind,
+        pattern: String,
+        must_match: bool,
+    },
+    VisibilityRule {
+        layer: String,
+        object_type: ItemKind,
+        visibility: String,
+        at_most: bool,
+    },
+    ForbiddenPaths {
+        layer: String,
+        paths: Vec<String>,
+    },
+    UnsafeOnlyIn {
+        layers: Vec<String>,
+    },
 }
@@ -0,0 +1,77 @@
+use std::fmt::{Display, Formatter};
+
+use arch_test_core::{RuleViolation, RuleViolationType};
+
+/// Identifies a violation in a baseline file independently of line numbers by the violated rule and
+/// * the path of the accessor and the path of the accessed object, if it is caused by a use,
+/// * the sorted members of the cycle, if it is a cycle,
+/// * the path of the module and the name of the object it is located at otherwise.
+///
+/// Entries are written without a tag and distinguished by their fields, which keeps entries of uses compatible with
+/// older baselines that recorded the name of the accessed object as `object` as well.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BaselineEntry {
+    Use {
+        rule: String,
+        accessor: String,
+        accessed: String,
+    },
+    Cycle {
+        rule: String,
+        members: Vec<String>,
+    },
+    Location {
+        rule: String,
+        module: String,
+        object: String,
+    },
+}
+
+impl From<&RuleViolation<'_>> for BaselineEntry {
+    fn from(violation: &RuleViolation<'_>) -> Self {
+        let rule = violation.rule_name().to_owned();
+        if violation.violation_type() == RuleViolationType::Cycle {
+            let mut members = violation.cycle_members().clone();
+            members.sort();
+            return BaselineEntry::Cycle { rule, members };
+        }
+        if let Some(use_relation) = violation.involved_object_uses().first() {
+            return BaselineEntry::Use {
+                rule,
+                accessor: use_relation.using_object().full_module_path().clone(),
+                accessed: use_relation.used_object().full_module_path().clone(),
+            };
+        }
+        let location = violation.locations().first();
+        BaselineEntry::Location {
+            rule,
+            module: location
+                .map(|location| location.module_path().clone())
+                .unwrap_or_default(),
+            object: location
+                .map(|location| location.object_name().clone())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl Display for BaselineEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BaselineEntry::Use {
+                rule,
+                accessor,
+                accessed,
+            } => write!(f, "{} | {} -> {}", rule, accessor, accessed),
+            BaselineEntry::Cycle { rule, members } => {
+                write!(f, "{} | {}", rule, members.join(", "))
+            }
+            BaselineEntry::Location {
+                rule,
+                module,
+                object,
+            } => write!(f, "{} | {} | {}", rule, module, object),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+/// How the violations found are compared with the baseline file `file_name` of a crate
+/// * `Write`: The violations found are stored as the new baseline
+/// * `Filter`: Violations contained in the baseline are not reported. If `strict`, entries that no longer occur fail the check
+#[derive(Debug, Clone)]
+pub enum BaselineMode {
+    Write { file_name: String },
+    Filter { file_name: String, strict: bool },
+}
+
+impl BaselineMode {
+    pub const DEFAULT_FILE_NAME: &'static str = "archtest_baseline.json";
+
+    /// Returns the mode selected by the command line arguments or `None` if no baseline is used.
+    /// Writing without a specified file uses `DEFAULT_FILE_NAME`.
+    pub fn from_arguments(
+        baseline: Option<String>,
+        write_baseline: bool,
+        strict: bool,
+    ) -> Option<Self> {
+        if write_baseline {
+            return Some(BaselineMode::Write {
+                file_name: baseline.unwrap_or_else(|| Self::DEFAULT_FILE_NAME.to_owned()),
+            });
+        }
+        baseline.map(|file_name| BaselineMode::Filter { file_name, strict })
+    }
+
+    pub fn file_name(&self) -> &String {
+        match self {
+            BaselineMode::Write { file_name } | BaselineMode::Filter { file_name, .. } => file_name,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use arch_test_core::CfgSet;
+
+use crate::domain_values::BaselineMode;
+
+/// Options of `check_architecture`, which are the same for every crate of the workspace.
+/// If a `cfg_set` is specified, only the code enabled by it is checked.
+/// If a `baseline_mode` is specified, the violations are written to or filtered by the baseline file of the crate.
+/// Warnings fail the check if `deny_warnings` is set and violations are colored with ANSI escape codes if `color` is set.
+#[derive(Debug, Clone, Default)]
+pub struct CheckOptions {
+    pub check_for_complete_layer_specification: bool,
+    pub cfg_set: Option<CfgSet>,
+    pub baseline_mode: Option<BaselineMode>,
+    pub deny_warnings: bool,
+    pub color: bool,
+}
@@ -0,0 +1,16 @@
+/// The outcome of checking a single crate, `valid` if the crate passed the check.
+/// The `warning_count` are the violations of rules with the severity `warning` that were reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CheckOutcome {
+    pub valid: bool,
+    pub warning_count: usize,
+}
+
+impl CheckOutcome {
+    pub fn new(valid: bool) -> Self {
+        CheckOutcome {
+            valid,
+            warning_count: 0,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use std::io::IsTerminal;
+use std::str::FromStr;
+
+/// Whether readable output is colored with ANSI escape codes
+/// * `Auto`: Colored if stdout is a terminal and `NO_COLOR` is not set, the default
+/// * `Always`: Always colored
+/// * `Never`: Never colored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorChoice {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            ColorChoice::Auto => {
+                std::env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal()
+            }
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+}
+
+impl FromStr for ColorChoice {
+    type Err = String;
+
+    fn from_str(color_choice: &str) -> Result<Self, Self::Err> {
+        match color_choice {
+            "auto" => Ok(ColorChoice::Auto),
+            "always" => Ok(ColorChoice::Always),
+            "never" => Ok(ColorChoice::Never),
+            _ => Err(format!(
+                "Unknown color choice '{}', expected 'auto', 'always' or 'never'",
+                color_choice
+            )),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::domain_values::{ColorChoice, OutputFormat};
+
 #[derive(Debug, StructOpt)]
 pub enum Command {
     Archtest {
@@ -16,5 +18,64 @@ pub enum Command {
             help = "Path to Cargo.toml"
         )]
         toml_path: String,
+        #[structopt(
+            short,
+            long,
+            about = "Evaluates cfg attributes for the host target, ignoring test code",
+            help = "Evaluates cfg attributes for the host target, ignoring test code"
+        )]
+        evaluate_cfg: bool,
+        #[structopt(
+            long,
+            use_delimiter = true,
+            about = "Enabled features, implies --evaluate-cfg",
+            help = "Enabled features, implies --evaluate-cfg"
+        )]
+        features: Vec<String>,
+        #[structopt(
+            long,
+            number_of_values = 1,
+            about = "Enabled cfg options like test or target_os=\"linux\", implies --evaluate-cfg",
+            help = "Enabled cfg options like test or target_os=\"linux\", implies --evaluate-cfg"
+        )]
+        cfg: Vec<String>,
+        #[structopt(
+            long,
+            about = "Baseline file of known violations that are not reported, relative to each crate",
+            help = "Baseline file of known violations that are not reported, relative to each crate"
+        )]
+        baseline: Option<String>,
+        #[structopt(
+            long,
+            about = "Writes all violations found to the baseline file, archtest_baseline.json by default",
+            help = "Writes all violations found to the baseline file, archtest_baseline.json by default"
+        )]
+        write_baseline: bool,
+        #[structopt(
+            long,
+            about = "Fails if entries of the baseline no longer occur, i.e. the baseline can be shrunk",
+            help = "Fails if entries of the baseline no longer occur, i.e. the baseline can be shrunk"
+        )]
+        strict_baseline: bool,
+        #[structopt(
+            long,
+            about = "Fails on violations of rules with the severity warning as well",
+            help = "Fails on violations of rules with the severity warning as well"
+        )]
+        deny_warnings: bool,
+        #[structopt(
+            long,
+            default_value = "human",
+            about = "Format of the reported violations: human, json, sarif or junit",
+            help = "Format of the reported violations: human, json, sarif or junit"
+        )]
+        format: OutputFormat,
+        #[structopt(
+            long,
+            default_value = "auto",
+            about = "Colors readable output: auto, always or never",
+            help = "Colors readable output: auto, always or never"
+        )]
+        color: ColorChoice,
     },
 }
@@ -0,0 +1,10 @@
+use arch_test_core::ArchitectureReport;
+
+/// The report of a single crate of the workspace, `valid` if the crate passed the check
+#[derive(Debug, Clone, Serialize)]
+pub struct CrateReport {
+    pub directory: String,
+    pub valid: bool,
+    #[serde(flatten)]
+    pub report: ArchitectureReport,
+}
@@ -1,5 +1,8 @@
 #[derive(Debug, Clone)]
 pub enum Failure {
-    SpecificationCouldNotBeParsed,
+    SpecificationCouldNotBeParsed(String),
     SpecificationFileCantBeOpened,
+    BaselineCouldNotBeParsed,
+    BaselineFileCantBeOpened,
+    BaselineFileCantBeWritten,
 }
@@ -0,0 +1,8 @@
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum ItemKind {
+    Struct,
+    Enum,
+    Function,
+    Trait,
+    TypeAlias,
+}
@@ -0,0 +1,11 @@
+use crate::domain_values::ItemKind;
+
+/// Selects items by a name pattern like `*Repository`, optionally narrowed down to kinds and a layer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemSpecification {
+    pub name: String,
+    #[serde(default)]
+    pub kinds: Vec<ItemKind>,
+    #[serde(default)]
+    pub layer: Option<String>,
+}
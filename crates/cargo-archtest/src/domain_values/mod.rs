@@ -1,9 +1,31 @@
 pub use self::access_rule::AccessRule;
+pub use self::baseline_entry::BaselineEntry;
+pub use self::baseline_mode::BaselineMode;
+pub use self::check_options::CheckOptions;
+pub use self::check_outcome::CheckOutcome;
+pub use self::color_choice::ColorChoice;
 pub use self::command::Command;
+pub use self::crate_report::CrateReport;
 pub use self::failure::Failure;
+pub use self::item_kind::ItemKind;
+pub use self::item_specification::ItemSpecification;
+pub use self::output_format::OutputFormat;
+pub use self::rule_specification::RuleSpecification;
+pub use self::severity::Severity;
 pub use self::specification::Specification;
 
 mod access_rule;
+mod baseline_entry;
+mod baseline_mode;
+mod check_options;
+mod check_outcome;
+mod color_choice;
 mod command;
+mod crate_report;
 mod failure;
+mod item_kind;
+mod item_specification;
+mod output_format;
+mod rule_specification;
+mod severity;
 mod specification;
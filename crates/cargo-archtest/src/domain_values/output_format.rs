@@ -0,0 +1,40 @@
+use std::str::FromStr;
+
+/// How the violations found are reported
+/// * `Human`: Readable text, the default
+/// * `Json`: A single JSON document containing the report of every crate, see `CrateReport`
+/// * `Sarif`: A SARIF 2.1.0 log with a run per crate for code scanning tools
+/// * `Junit`: JUnit XML with a test suite per crate and a test case per access rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    #[default]
+    Human,
+    Json,
+    Sarif,
+    Junit,
+}
+
+impl OutputFormat {
+    /// Returns `true` if the report is meant to be consumed by other programs.
+    /// Messages that are not part of the report are then written to stderr.
+    pub fn is_machine_readable(&self) -> bool {
+        *self != OutputFormat::Human
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "human" => Ok(OutputFormat::Human),
+            "json" => Ok(OutputFormat::Json),
+            "sarif" => Ok(OutputFormat::Sarif),
+            "junit" => Ok(OutputFormat::Junit),
+            _ => Err(format!(
+                "Unknown format '{}', expected 'human', 'json', 'sarif' or 'junit'",
+                format
+            )),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use crate::domain_values::{AccessRule, Severity};
+
+/// An access rule, optionally with a severity, e.g. `{ "rule": "NoParentAccess", "severity": "warning" }`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RuleSpecification {
+    WithSeverity { rule: AccessRule, severity: Severity },
+    Plain(AccessRule),
+}
@@ -1,7 +1,11 @@
-use crate::domain_values::AccessRule;
+use std::collections::HashMap;
+
+use crate::domain_values::RuleSpecification;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Specification {
     pub layer_names: Vec<String>,
-    pub access_rules: Vec<AccessRule>,
+    #[serde(default)]
+    pub external_layers: HashMap<String, Vec<String>>,
+    pub access_rules: Vec<RuleSpecification>,
 }
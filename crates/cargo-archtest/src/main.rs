@@ -44,7 +44,46 @@
 //!   ]
 //! }
 //! ```
+//! External crates can be declared as layers by adding `"external_layers": { "infrastructure": ["diesel", "sqlx"] }`.
+//! Rules may then refer to `infrastructure` or to the crate names directly.
+//!
+//! Rules between individual items select them by name pattern, optionally narrowed down to kinds and a layer, e.g.
+//! `{ "ItemMayOnlyBeAccessedBy": { "accessed": { "name": "*", "layer": "infrastructure" }, "accessors": [{ "name": "*Repository", "kinds": ["Struct"] }] } }`.
+//!
+//! Naming conventions are checked per layer and object type, e.g. structs in `services` end with `Service`:
+//! `{ "NamingConvention": { "layer": "services", "object_type": "Struct", "pattern": "*Service", "must_match": true } }`.
+//!
+//! Visibilities are restricted per layer and object type to at most or at least `private`, `pub(super)`, `pub(crate)` or `pub`:
+//! `{ "VisibilityRule": { "layer": "internal", "object_type": "Struct", "visibility": "pub(crate)", "at_most": true } }`.
+//!
+//! Paths can be forbidden in a layer, e.g. `{ "ForbiddenPaths": { "layer": "domain", "paths": ["std::fs", "println!", "Instant::now"] } }`.
+//! Unsafe code can be restricted to layers, e.g. `{ "UnsafeOnlyIn": { "layers": ["ffi", "sys"] } }`.
+//! Individual violations are suppressed in the source by `// archtest-allow(MayNotAccess): reason` or `#[cfg_attr(archtest, allow(MayNotAccess))]`.
+//! Each access rule can be given a severity `error`, `warning` or `off`, e.g. `{ "rule": "NoParentAccess", "severity": "warning" }`.
+//! Warnings are reported without failing the check, unless `--deny-warnings` is specified.
+//! Cycles between named layers are forbidden by `{ "NoNamedLayerCyclicDependencies": { "layers": ["services", "materials"] } }`, omitting `layers` considers all `layer_names`.
+//!
 //! Then execute `cargo archtest` in your project directory.
+//! In order to check the production build instead of all code, execute `cargo archtest --evaluate-cfg`.
+//! Features and further cfg options can be enabled with `--features a,b` and `--cfg test`.
+//!
+//! ## Baseline
+//! To adopt ArchTest on an existing code base, `cargo archtest --write-baseline` records all current violations in `archtest_baseline.json`.
+//! `cargo archtest --baseline archtest_baseline.json` then only fails on new violations and reports baseline entries that no longer occur.
+//! With `--strict-baseline` it also fails if the baseline can be shrunk.
+//!
+//! ## Output formats
+//! `cargo archtest --format json` prints a single JSON document instead of readable text, e.g. for dashboards and bots.
+//! It contains the report of every crate with its violations, each with the kind and parameters of the violated rule,
+//! its severity and the files, module paths, object names and line/column spans of the involved objects.
+//! `cargo archtest --format sarif > archtest.sarif` writes a SARIF 2.1.0 log instead, so that code scanning tools show violations inline.
+//! Each access rule is a SARIF rule and each result points to the accessor, with the accessed object and the further hops of a cycle as related locations.
+//! `cargo archtest --format junit > archtest.xml` writes JUnit XML with a test suite per crate and a test case per access rule,
+//! so that a violated rule shows up as a failed test under its name. The failure message contains the rendered violations.
+//! Other messages are written to stderr.
+//!
+//! Readable violations are rendered like compiler errors with the source lines of the involved objects.
+//! They are colored if stdout is a terminal, which can be overridden with `--color always` or `--color never`.
 //!
 //! ## Continuous integration
 //! You can use it in continuous integration by using either methods. If you decide to use the Cargo sub command on GitHub, the following snippet will allow you to test your project.
@@ -73,8 +112,10 @@ use std::path::Path;
 
 use structopt::StructOpt;
 
-use crate::domain_values::Command;
-use crate::services::check_architecture;
+use crate::domain_values::{BaselineMode, CheckOptions, Command, OutputFormat};
+use crate::services::{
+    check_architecture, create_cfg_set, render_json, render_junit, render_sarif,
+};
 
 mod domain_values;
 mod services;
@@ -86,8 +127,32 @@ fn main() {
     let Command::Archtest {
         check_for_complete_layer_specification,
         toml_path,
+        evaluate_cfg,
+        features,
+        cfg,
+        baseline,
+        write_baseline,
+        strict_baseline,
+        deny_warnings,
+        format,
+        color,
     } = Command::from_args();
+    let options = CheckOptions {
+        check_for_complete_layer_specification,
+        cfg_set: create_cfg_set(evaluate_cfg, &features, &cfg),
+        baseline_mode: BaselineMode::from_arguments(baseline, write_baseline, strict_baseline),
+        deny_warnings,
+        color: color.is_enabled(),
+    };
     let toml_path = Path::new(&toml_path);
+    let mut is_valid = true;
+    let mut warning_count = 0;
+    let mut crate_reports = Vec::new();
+    let mut crate_reports = if format.is_machine_readable() {
+        Some(&mut crate_reports)
+    } else {
+        None
+    };
     if toml_path.exists() && toml_path.is_file() {
         if let Ok(toml) = cargo_toml::Manifest::from_path(toml_path) {
             if let Some(workspace) = toml.workspace {
@@ -96,11 +161,16 @@ fn main() {
                         println!("Can not interpret paths with '*'");
                         std::process::exit(1);
                     } else {
-                        check_architecture(&member, check_for_complete_layer_specification);
+                        let outcome =
+                            check_architecture(&member, &options, crate_reports.as_deref_mut());
+                        is_valid &= outcome.valid;
+                        warning_count += outcome.warning_count;
                     }
                 }
             } else {
-                check_architecture(".", check_for_complete_layer_specification);
+                let outcome = check_architecture(".", &options, crate_reports.as_deref_mut());
+                is_valid = outcome.valid;
+                warning_count = outcome.warning_count;
             }
         } else {
             println!("Cargo.toml could not be parsed!");
@@ -111,5 +181,29 @@ fn main() {
         std::process::exit(1);
     }
 
-    println!("[Ok]: No architecture rules were violated!");
+    if let Some(crate_reports) = crate_reports {
+        match format {
+            OutputFormat::Json => println!("{}", render_json(crate_reports)),
+            OutputFormat::Sarif => println!("{}", render_sarif(crate_reports)),
+            OutputFormat::Junit => print!("{}", render_junit(crate_reports, deny_warnings)),
+            OutputFormat::Human => {}
+        }
+    }
+    if !is_valid {
+        std::process::exit(1);
+    }
+    if format.is_machine_readable() {
+        return;
+    }
+    if let Some(BaselineMode::Write { .. }) = options.baseline_mode {
+        return;
+    }
+    if warning_count > 0 {
+        println!(
+            "[Ok]: No architecture rules were violated, but {} warning(s) were reported.",
+            warning_count
+        );
+    } else {
+        println!("[Ok]: No architecture rules were violated!");
+    }
 }
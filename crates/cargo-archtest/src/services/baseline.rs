@@ -0,0 +1,56 @@
+use std::path::Path;
+
+use arch_test_core::ViolationReport;
+
+use crate::domain_values::{BaselineEntry, Failure};
+
+/// Reads the entries of the baseline file at `baseline_path`
+pub fn read_baseline(baseline_path: &Path) -> Result<Vec<BaselineEntry>, Failure> {
+    let content =
+        std::fs::read_to_string(baseline_path).map_err(|_| Failure::BaselineFileCantBeOpened)?;
+    serde_json::from_str(&content).map_err(|_| Failure::BaselineCouldNotBeParsed)
+}
+
+/// Writes an entry for every violation of the `report` in sorted order to `baseline_path`.
+/// Returns the number of entries written.
+pub fn write_baseline(
+    baseline_path: &Path,
+    report: Option<&ViolationReport<'_>>,
+) -> Result<usize, Failure> {
+    let mut entries: Vec<BaselineEntry> = report
+        .into_iter()
+        .flat_map(|report| report.violations())
+        .map(BaselineEntry::from)
+        .collect();
+    entries.sort();
+    let content = serde_json::to_string_pretty(&entries).unwrap();
+    std::fs::write(baseline_path, content + "\n")
+        .map_err(|_| Failure::BaselineFileCantBeWritten)?;
+    Ok(entries.len())
+}
+
+/// Removes the violations of the `report` that are contained in the `baseline`, each entry matching a single violation.
+/// Returns the entries that no longer occur in sorted order.
+pub fn apply_baseline(
+    report: Option<&mut ViolationReport<'_>>,
+    baseline: Vec<BaselineEntry>,
+) -> Vec<BaselineEntry> {
+    let mut remaining = baseline;
+    if let Some(report) = report {
+        report.retain(|violation| {
+            let entry = BaselineEntry::from(violation);
+            match remaining
+                .iter()
+                .position(|remaining_entry| *remaining_entry == entry)
+            {
+                Some(position) => {
+                    remaining.swap_remove(position);
+                    false
+                }
+                None => true,
+            }
+        });
+    }
+    remaining.sort();
+    remaining
+}
@@ -1,10 +1,23 @@
 use std::path::Path;
 
-use arch_test_core::ModuleTree;
+use arch_test_core::{ArchitectureReport, ModuleTree, SourceFiles};
 
-use crate::services::parse_specification;
+use crate::domain_values::{BaselineMode, CheckOptions, CheckOutcome, CrateReport, Failure};
+use crate::services::{apply_baseline, parse_specification, read_baseline, write_baseline};
 
-pub fn check_architecture(directory_path: &str, check_for_complete_layer_specification: bool) {
+/// Checks the architecture of the crate in `directory_path` with the `options` and prints every violation found.
+/// If `crate_reports` are specified, the report of the crate is added to them instead of being printed
+/// and other messages are written to stderr.
+/// Violations are printed like compiler errors.
+/// The crate is valid if no violation was found, except for warnings if they are not denied by `deny_warnings`.
+/// A crate without specification file is skipped, a specification that can not be parsed makes it invalid.
+pub fn check_architecture(
+    directory_path: &str,
+    options: &CheckOptions,
+    crate_reports: Option<&mut Vec<CrateReport>>,
+) -> CheckOutcome {
+    let color = options.color;
+    let is_machine_readable = crate_reports.is_some();
     let main_path_str = format!("{}/src/main.rs", directory_path);
     let main_path = Path::new(&main_path_str);
     let root_path = if main_path.exists() && main_path.is_file() {
@@ -13,26 +26,155 @@ pub fn check_architecture(directory_path: &str, check_for_complete_layer_specifi
         format!("{}/src/lib.rs", directory_path)
     };
     let specification_path = format!("{}/architecture.json", directory_path);
-    let specification = parse_specification(Path::new(&specification_path));
+    let architecture = match parse_specification(Path::new(&specification_path)) {
+        Ok(architecture) => architecture,
+        Err(Failure::SpecificationCouldNotBeParsed(message)) => {
+            print_message(
+                &format!(
+                    "Specification file cant be parsed for '{}': {}",
+                    directory_path, message
+                ),
+                is_machine_readable,
+            );
+            return CheckOutcome::new(false);
+        }
+        Err(_) => {
+            print_message(
+                &format!(
+                    "Specification file cant be opened for '{}'.",
+                    directory_path
+                ),
+                is_machine_readable,
+            );
+            return CheckOutcome::new(true);
+        }
+    };
+
+    let module_tree = match options.cfg_set.as_ref() {
+        Some(cfg_set) => ModuleTree::new_with_cfg(&root_path, cfg_set),
+        None => ModuleTree::new(&root_path),
+    };
+    if let Err(err) = architecture.validate_access_rules() {
+        match crate_reports {
+            Some(crate_reports) => crate_reports.push(CrateReport {
+                directory: directory_path.to_owned(),
+                valid: false,
+                report: ArchitectureReport::new(vec![&err], module_tree.tree())
+                    .with_rules(architecture.rule_records()),
+            }),
+            None => print!(
+                "{}",
+                err.render(module_tree.tree(), &mut SourceFiles::new(), color)
+            ),
+        }
+        return CheckOutcome::new(false);
+    }
+
+    let mut is_valid = true;
+    let mut report = architecture.check_all_access_rules(&module_tree).err();
+    if let Some(baseline_mode) = options.baseline_mode.as_ref() {
+        let baseline_path = Path::new(directory_path).join(baseline_mode.file_name());
+        let baseline_path = baseline_path.to_str().unwrap();
+        match baseline_mode {
+            BaselineMode::Write { .. } => {
+                return match write_baseline(Path::new(baseline_path), report.as_ref()) {
+                    Ok(count) => {
+                        print_message(
+                            &format!("Wrote {} violation(s) to '{}'.", count, baseline_path),
+                            is_machine_readable,
+                        );
+                        CheckOutcome::new(true)
+                    }
+                    Err(_) => {
+                        print_message(
+                            &format!("Baseline file cant be written to '{}'.", baseline_path),
+                            is_machine_readable,
+                        );
+                        CheckOutcome::new(false)
+                    }
+                };
+            }
+            BaselineMode::Filter { strict, .. } => {
+                let baseline = match read_baseline(Path::new(baseline_path)) {
+                    Ok(baseline) => baseline,
+                    Err(_) => {
+                        print_message(
+                            &format!("Baseline file cant be read from '{}'.", baseline_path),
+                            is_machine_readable,
+                        );
+                        return CheckOutcome::new(false);
+                    }
+                };
+                let outdated_entries = apply_baseline(report.as_mut(), baseline);
+                for entry in outdated_entries.iter() {
+                    print_message(
+                        &format!("Baseline entry no longer occurs: {}", entry),
+                        is_machine_readable,
+                    );
+                }
+                if *strict && !outdated_entries.is_empty() {
+                    print_message(
+                        &format!(
+                            "The baseline '{}' can be shrunk by {} entries.",
+                            baseline_path,
+                            outdated_entries.len()
+                        ),
+                        is_machine_readable,
+                    );
+                    is_valid = false;
+                }
+            }
+        }
+    }
+    let report = report.filter(|report| !report.is_empty());
+    if let Some(report) = report.as_ref() {
+        is_valid &= !report.has_errors() && !options.deny_warnings;
+    }
+    let incomplete_layer_specification = if options.check_for_complete_layer_specification {
+        architecture
+            .check_complete_layer_specification(&module_tree)
+            .err()
+    } else {
+        None
+    };
+    is_valid &= incomplete_layer_specification.is_none();
 
-    if let Ok(architecture) = specification {
-        let module_tree = ModuleTree::new(&root_path);
-        if let Err(err) = architecture.validate_access_rules() {
-            err.print(module_tree.tree());
-            std::process::exit(1);
-        } else if let Err(err) = architecture.check_access_rules(&module_tree) {
-            err.print(module_tree.tree());
-            std::process::exit(1);
-        } else if check_for_complete_layer_specification {
-            if let Err(err) = architecture.check_complete_layer_specification(&module_tree) {
-                err.print(module_tree.tree());
-                std::process::exit(1);
+    match crate_reports {
+        Some(crate_reports) => crate_reports.push(CrateReport {
+            directory: directory_path.to_owned(),
+            valid: is_valid,
+            report: ArchitectureReport::new(
+                report
+                    .iter()
+                    .flat_map(|report| report.violations())
+                    .chain(incomplete_layer_specification.iter()),
+                module_tree.tree(),
+            )
+            .with_rules(architecture.rule_records()),
+        }),
+        None => {
+            if let Some(report) = report.as_ref() {
+                print!("{}", report.render(module_tree.tree(), color));
+            }
+            if let Some(err) = incomplete_layer_specification.as_ref() {
+                print!(
+                    "{}",
+                    err.render(module_tree.tree(), &mut SourceFiles::new(), color)
+                );
             }
         }
+    }
+    CheckOutcome {
+        valid: is_valid,
+        warning_count: report.map_or(0, |report| report.warning_count()),
+    }
+}
+
+/// Prints a message that is not part of the report, to stderr if the report is machine readable
+fn print_message(message: &str, is_machine_readable: bool) {
+    if is_machine_readable {
+        eprintln!("{}", message);
     } else {
-        println!(
-            "Specification file cant be opened for '{}'.",
-            directory_path
-        );
+        println!("{}", message);
     }
 }
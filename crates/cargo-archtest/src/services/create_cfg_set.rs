@@ -0,0 +1,24 @@
+use arch_test_core::CfgSet;
+
+/// Creates the `CfgSet` of the host target with the specified `features` and `cfgs`.
+/// A cfg is either an option like `test` or a key value pair like `target_os="linux"`.
+/// Returns `None` if `cfg` attributes should not be evaluated.
+pub fn create_cfg_set(evaluate_cfg: bool, features: &[String], cfgs: &[String]) -> Option<CfgSet> {
+    if !evaluate_cfg && features.is_empty() && cfgs.is_empty() {
+        return None;
+    }
+    let mut cfg_set = CfgSet::host();
+    for feature in features {
+        cfg_set = cfg_set.with_feature(feature.clone());
+    }
+    for cfg in cfgs {
+        cfg_set = match cfg.find('=') {
+            Some(position) => cfg_set.with_key_value(
+                cfg[..position].trim().to_owned(),
+                cfg[(position + 1)..].trim().trim_matches('"').to_owned(),
+            ),
+            None => cfg_set.with_option(cfg.trim().to_owned()),
+        };
+    }
+    Some(cfg_set)
+}
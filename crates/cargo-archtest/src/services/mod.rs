@@ -1,5 +1,17 @@
+pub use self::baseline::{apply_baseline, read_baseline, write_baseline};
 pub use self::check_architecture::check_architecture;
+pub use self::create_cfg_set::create_cfg_set;
 pub use self::parse_specification::parse_specification;
+pub use self::render_junit::render_junit;
+pub use self::render_report::{
+    render_json, rule_ids, violation_message, violation_type_description,
+};
+pub use self::render_sarif::render_sarif;
 
+mod baseline;
 mod check_architecture;
+mod create_cfg_set;
 mod parse_specification;
+mod render_junit;
+mod render_report;
+mod render_sarif;
@@ -1,82 +1,268 @@
 use std::fs::File;
 use std::io::Read;
+use std::iter::once;
 use std::path::Path;
 
 use arch_test_core::access_rules::{
-    MayNotAccess, MayNotBeAccessedBy, MayOnlyAccess, MayOnlyBeAccessedBy,
-    NoLayerCyclicDependencies, NoModuleCyclicDependencies, NoParentAccess,
+    ForbiddenPaths, ItemMayNotAccess, ItemMayOnlyBeAccessedBy, LayeredArchitecture, MayNotAccess,
+    MayNotBeAccessedBy, MayOnlyAccess, MayOnlyBeAccessedBy, NamingConvention,
+    NoLayerCyclicDependencies, NoModuleCyclicDependencies, NoNamedLayerCyclicDependencies,
+    NoParentAccess, OnionArchitecture, UnsafeOnlyIn, VisibilityRule,
 };
 use arch_test_core::hash_set;
-use arch_test_core::Architecture;
+use arch_test_core::{Architecture, ItemSelector, LayerSelector, ObjectType, Severity, Visibility};
 
-use crate::domain_values::{AccessRule, Failure, Specification};
+use crate::domain_values::{
+    AccessRule, Failure, ItemKind, ItemSpecification, RuleSpecification, Severity as SeverityLevel,
+    Specification,
+};
 
-pub fn parse_specification(specification_path: &Path) -> Result<Architecture, Failure> {
+pub fn parse_specification(specification_path: &Path) -> Result<Architecture<'static>, Failure> {
     let specification: Specification =
         serde_json::from_str(&read_file_content(specification_path)?)
-            .map_err(|_| Failure::SpecificationCouldNotBeParsed)?;
+            .map_err(|err| Failure::SpecificationCouldNotBeParsed(err.to_string()))?;
 
+    validate_layers(specification.layer_names.iter())?;
     let mut architecture = Architecture::new(hash_set![..specification.clone().layer_names]);
-    for access_rule in specification.access_rules {
+    for (layer_name, crate_names) in specification.external_layers {
+        architecture = architecture.with_external_layer(layer_name, hash_set![..crate_names]);
+    }
+    for rule_specification in specification.access_rules {
+        let (access_rule, severity) = match rule_specification {
+            RuleSpecification::WithSeverity { rule, severity } => (rule, to_severity(severity)),
+            RuleSpecification::Plain(rule) => (rule, Severity::Error),
+        };
         match access_rule {
             AccessRule::NoLayerCyclicDependencies => {
-                architecture = architecture.with_access_rule(NoLayerCyclicDependencies)
+                architecture =
+                    architecture.with_access_rule_and_severity(NoLayerCyclicDependencies, severity)
             }
             AccessRule::NoModuleCyclicDependencies => {
-                architecture = architecture.with_access_rule(NoModuleCyclicDependencies)
+                architecture =
+                    architecture.with_access_rule_and_severity(NoModuleCyclicDependencies, severity)
+            }
+            AccessRule::NoNamedLayerCyclicDependencies { layers } => {
+                let layers = if layers.is_empty() {
+                    specification.layer_names.clone()
+                } else {
+                    layers
+                };
+                validate_layers(layers.iter())?;
+                architecture = architecture.with_access_rule_and_severity(
+                    NoNamedLayerCyclicDependencies::new(hash_set![..layers]),
+                    severity,
+                )
             }
             AccessRule::NoParentAccess => {
-                architecture = architecture.with_access_rule(NoParentAccess)
+                architecture = architecture.with_access_rule_and_severity(NoParentAccess, severity)
             }
             AccessRule::MayOnlyAccess {
                 accessor,
                 accessed,
                 when_same_parent,
             } => {
-                architecture = architecture.with_access_rule(MayOnlyAccess::new(
-                    accessor,
-                    hash_set![..accessed],
-                    when_same_parent,
-                ))
+                validate_layers(once(&accessor).chain(accessed.iter()))?;
+                architecture = architecture.with_access_rule_and_severity(
+                    MayOnlyAccess::new(accessor, hash_set![..accessed], when_same_parent),
+                    severity,
+                )
             }
             AccessRule::MayNotAccess {
                 accessor,
                 accessed,
                 when_same_parent,
             } => {
-                architecture = architecture.with_access_rule(MayNotAccess::new(
-                    accessor,
-                    hash_set![..accessed],
-                    when_same_parent,
-                ))
+                validate_layers(once(&accessor).chain(accessed.iter()))?;
+                architecture = architecture.with_access_rule_and_severity(
+                    MayNotAccess::new(accessor, hash_set![..accessed], when_same_parent),
+                    severity,
+                )
             }
             AccessRule::MayOnlyBeAccessedBy {
                 accessors,
                 accessed,
                 when_same_parent,
             } => {
-                architecture = architecture.with_access_rule(MayOnlyBeAccessedBy::new(
-                    accessed,
-                    hash_set![..accessors],
-                    when_same_parent,
-                ))
+                validate_layers(once(&accessed).chain(accessors.iter()))?;
+                architecture = architecture.with_access_rule_and_severity(
+                    MayOnlyBeAccessedBy::new(accessed, hash_set![..accessors], when_same_parent),
+                    severity,
+                )
             }
             AccessRule::MayNotBeAccessedBy {
                 accessors,
                 accessed,
                 when_same_parent,
             } => {
-                architecture = architecture.with_access_rule(MayNotBeAccessedBy::new(
-                    accessed,
-                    hash_set![..accessors],
-                    when_same_parent,
-                ))
+                validate_layers(once(&accessed).chain(accessors.iter()))?;
+                architecture = architecture.with_access_rule_and_severity(
+                    MayNotBeAccessedBy::new(accessed, hash_set![..accessors], when_same_parent),
+                    severity,
+                )
+            }
+            AccessRule::LayeredArchitecture { layers, strict } => {
+                validate_layers(layers.iter())?;
+                architecture = architecture.with_access_rule_and_severity(
+                    LayeredArchitecture::new(layers, strict),
+                    severity,
+                )
+            }
+            AccessRule::OnionArchitecture {
+                domain_model,
+                domain_services,
+                application_services,
+                adapters,
+            } => {
+                validate_layers(
+                    domain_model
+                        .iter()
+                        .chain(domain_services.iter())
+                        .chain(application_services.iter())
+                        .chain(adapters.iter()),
+                )?;
+                architecture = architecture.with_access_rule_and_severity(
+                    OnionArchitecture::new(
+                        domain_model,
+                        domain_services,
+                        application_services,
+                        adapters,
+                    ),
+                    severity,
+                )
+            }
+            AccessRule::ItemMayNotAccess { accessor, accessed } => {
+                validate_layers(
+                    once(&accessor)
+                        .chain(accessed.iter())
+                        .filter_map(|item| item.layer.as_ref()),
+                )?;
+                architecture = architecture.with_access_rule_and_severity(
+                    ItemMayNotAccess::new(
+                        item_selector(accessor),
+                        accessed.into_iter().map(item_selector).collect(),
+                    ),
+                    severity,
+                )
+            }
+            AccessRule::ItemMayOnlyBeAccessedBy {
+                accessed,
+                accessors,
+            } => {
+                validate_layers(
+                    once(&accessed)
+                        .chain(accessors.iter())
+                        .filter_map(|item| item.layer.as_ref()),
+                )?;
+                architecture = architecture.with_access_rule_and_severity(
+                    ItemMayOnlyBeAccessedBy::new(
+                        item_selector(accessed),
+                        accessors.into_iter().map(item_selector).collect(),
+                    ),
+                    severity,
+                )
+            }
+            AccessRule::NamingConvention {
+                layer,
+                object_type,
+                pattern,
+                must_match,
+            } => {
+                validate_layers(once(&layer))?;
+                architecture = architecture.with_access_rule_and_severity(
+                    NamingConvention::new(layer, to_object_type(object_type), pattern, must_match),
+                    severity,
+                )
+            }
+            AccessRule::VisibilityRule {
+                layer,
+                object_type,
+                visibility,
+                at_most,
+            } => {
+                validate_layers(once(&layer))?;
+                let object_type = to_object_type(object_type);
+                let visibility = to_visibility(&visibility)?;
+                architecture = architecture.with_access_rule_and_severity(
+                    if at_most {
+                        VisibilityRule::at_most(layer, object_type, visibility)
+                    } else {
+                        VisibilityRule::at_least(layer, object_type, visibility)
+                    },
+                    severity,
+                )
+            }
+            AccessRule::ForbiddenPaths { layer, paths } => {
+                validate_layers(once(&layer))?;
+                architecture = architecture.with_access_rule_and_severity(
+                    ForbiddenPaths::new(layer, hash_set![..paths]),
+                    severity,
+                )
+            }
+            AccessRule::UnsafeOnlyIn { layers } => {
+                validate_layers(layers.iter())?;
+                architecture = architecture
+                    .with_access_rule_and_severity(UnsafeOnlyIn::new(hash_set![..layers]), severity)
             }
         }
     }
     Ok(architecture)
 }
 
+/// Fails if any of the `layers` is not a valid `LayerSelector`, e.g. a `regex:` selector with an invalid regular expression
+fn validate_layers<'a>(layers: impl Iterator<Item = &'a String>) -> Result<(), Failure> {
+    for layer in layers {
+        LayerSelector::parse(layer).map_err(|err| {
+            Failure::SpecificationCouldNotBeParsed(format!(
+                "The layer selector `{}` is invalid: {}",
+                layer, err
+            ))
+        })?;
+    }
+    Ok(())
+}
+
+fn item_selector(item_specification: ItemSpecification) -> ItemSelector {
+    let mut item_selector = ItemSelector::new(&item_specification.name);
+    for kind in item_specification.kinds {
+        item_selector = item_selector.with_kind(to_object_type(kind));
+    }
+    if let Some(layer) = item_specification.layer {
+        item_selector = item_selector.with_layer(&layer);
+    }
+    item_selector
+}
+
+fn to_object_type(kind: ItemKind) -> ObjectType {
+    match kind {
+        ItemKind::Struct => ObjectType::Struct,
+        ItemKind::Enum => ObjectType::Enum,
+        ItemKind::Function => ObjectType::Function,
+        ItemKind::Trait => ObjectType::Trait,
+        ItemKind::TypeAlias => ObjectType::TypeAlias,
+    }
+}
+
+fn to_severity(severity: SeverityLevel) -> Severity {
+    match severity {
+        SeverityLevel::Error => Severity::Error,
+        SeverityLevel::Warning => Severity::Warning,
+        SeverityLevel::Off => Severity::Off,
+    }
+}
+
+/// Parses `private` or a visibility like `pub(crate)`
+fn to_visibility(visibility: &str) -> Result<Visibility, Failure> {
+    if visibility.trim() == "private" {
+        return Ok(Visibility::Private);
+    }
+    Visibility::parse(visibility).ok_or_else(|| {
+        Failure::SpecificationCouldNotBeParsed(format!(
+            "The visibility `{}` is unknown",
+            visibility
+        ))
+    })
+}
+
 fn read_file_content(file_path: &Path) -> Result<String, Failure> {
     let mut file = File::open(file_path).map_err(|_| Failure::SpecificationFileCantBeOpened)?;
     let mut content = String::new();
@@ -0,0 +1,157 @@
+use arch_test_core::{Severity, SourceFiles, ViolationRecord};
+
+use crate::domain_values::CrateReport;
+use crate::services::rule_ids;
+
+/// Renders the reports of all crates as JUnit XML with a test suite per crate and a test case per access rule.
+/// Violations that are not caused by an access rule, e.g. unused suppressions, are assigned to a test case named after their `RuleViolationType`.
+/// A test case fails if its rule has violations with the severity `Error`, or `Warning` if warnings are denied.
+/// Otherwise the violations are contained in the output of the test case. Violations are rendered like compiler errors. Rules with the severity `Off` are skipped.
+pub fn render_junit(crate_reports: &[CrateReport], deny_warnings: bool) -> String {
+    let test_suites: Vec<TestSuite> = crate_reports
+        .iter()
+        .map(|crate_report| test_suite(crate_report, deny_warnings))
+        .collect();
+    let mut source_files = SourceFiles::new();
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuites name=\"cargo-archtest\" tests=\"{}\" failures=\"{}\">\n",
+        test_suites
+            .iter()
+            .map(|test_suite| test_suite.test_cases.len())
+            .sum::<usize>(),
+        test_suites
+            .iter()
+            .map(TestSuite::failure_count)
+            .sum::<usize>()
+    ));
+    for test_suite in test_suites.iter() {
+        xml.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">\n",
+            escape_xml(&test_suite.name),
+            test_suite.test_cases.len(),
+            test_suite.failure_count(),
+            test_suite
+                .test_cases
+                .iter()
+                .filter(|test_case| test_case.severity == Severity::Off)
+                .count()
+        ));
+        for test_case in test_suite.test_cases.iter() {
+            xml.push_str(&format!(
+                "    <testcase classname=\"{}\" name=\"{}\"",
+                escape_xml(&test_suite.name),
+                escape_xml(&test_case.name)
+            ));
+            if test_case.severity == Severity::Off {
+                xml.push_str(">\n      <skipped/>\n    </testcase>\n");
+            } else if test_case.violations.is_empty() {
+                xml.push_str("/>\n");
+            } else if test_case.is_failed(deny_warnings) {
+                xml.push_str(&format!(
+                    ">\n      <failure message=\"{} violation(s)\" type=\"{}\">{}</failure>\n    </testcase>\n",
+                    test_case.violations.len(),
+                    escape_xml(&test_case.name),
+                    escape_xml(&render_violations(&test_case.violations, &mut source_files))
+                ));
+            } else {
+                xml.push_str(&format!(
+                    ">\n      <system-out>{}</system-out>\n    </testcase>\n",
+                    escape_xml(&render_violations(&test_case.violations, &mut source_files))
+                ));
+            }
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+struct TestSuite<'a> {
+    name: String,
+    test_cases: Vec<TestCase<'a>>,
+    deny_warnings: bool,
+}
+
+impl TestSuite<'_> {
+    fn failure_count(&self) -> usize {
+        self.test_cases
+            .iter()
+            .filter(|test_case| test_case.is_failed(self.deny_warnings))
+            .count()
+    }
+}
+
+struct TestCase<'a> {
+    name: String,
+    severity: Severity,
+    violations: Vec<&'a ViolationRecord>,
+}
+
+impl TestCase<'_> {
+    fn is_failed(&self, deny_warnings: bool) -> bool {
+        self.violations.iter().any(|violation| {
+            violation.severity() == Severity::Error
+                || (deny_warnings && violation.severity() == Severity::Warning)
+        })
+    }
+}
+
+fn test_suite(crate_report: &CrateReport, deny_warnings: bool) -> TestSuite<'_> {
+    let report = &crate_report.report;
+    let mut test_cases: Vec<TestCase> = report
+        .rules()
+        .iter()
+        .zip(rule_ids(report.rules()))
+        .map(|(rule, name)| TestCase {
+            name,
+            severity: rule.severity(),
+            violations: Vec::new(),
+        })
+        .collect();
+    for violation in report.violations() {
+        let test_case_index = match violation.rule_index() {
+            Some(rule_index) => rule_index,
+            None => {
+                let name = format!("{:?}", violation.violation_type());
+                match test_cases
+                    .iter()
+                    .skip(report.rules().len())
+                    .position(|test_case| test_case.name == name)
+                {
+                    Some(position) => report.rules().len() + position,
+                    None => {
+                        test_cases.push(TestCase {
+                            name,
+                            severity: violation.severity(),
+                            violations: Vec::new(),
+                        });
+                        test_cases.len() - 1
+                    }
+                }
+            }
+        };
+        test_cases[test_case_index].violations.push(violation);
+    }
+    TestSuite {
+        name: crate_report.directory.clone(),
+        test_cases,
+        deny_warnings,
+    }
+}
+
+fn render_violations(violations: &[&ViolationRecord], source_files: &mut SourceFiles) -> String {
+    violations
+        .iter()
+        .map(|violation| violation.render(source_files, false))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
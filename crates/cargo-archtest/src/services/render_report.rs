@@ -0,0 +1,51 @@
+use arch_test_core::{RuleRecord, RuleViolationType, ViolationRecord};
+use serde_json::json;
+
+use crate::domain_values::CrateReport;
+
+/// Renders the reports of all crates as a single JSON document, which is `valid` if every crate is valid.
+pub fn render_json(crate_reports: &[CrateReport]) -> String {
+    serde_json::to_string_pretty(&json!({
+        "valid": crate_reports.iter().all(|crate_report| crate_report.valid),
+        "crates": crate_reports,
+    }))
+    .unwrap()
+}
+
+/// Returns an id per rule: the kind of the rule, numbered if several rules are of the same kind, e.g. `MayNotAccess-2`
+pub fn rule_ids(rules: &[RuleRecord]) -> Vec<String> {
+    let kinds: Vec<&String> = rules.iter().map(|rule| rule.rule().kind()).collect();
+    kinds
+        .iter()
+        .enumerate()
+        .map(|(index, kind)| {
+            if kinds.iter().filter(|other| *other == kind).count() == 1 {
+                return kind.to_string();
+            }
+            let occurrence = kinds[..index].iter().filter(|other| *other == kind).count() + 1;
+            format!("{}-{}", kind, occurrence)
+        })
+        .collect()
+}
+
+/// Describes what violations of the `violation_type` have in common, if they are not caused by an access rule
+pub fn violation_type_description(violation_type: RuleViolationType) -> &'static str {
+    match violation_type {
+        RuleViolationType::UnusedSuppression => "Suppressions must suppress a violation",
+        RuleViolationType::IncompleteLayerSpecification => {
+            "Every module must be covered by a layer"
+        }
+        RuleViolationType::LayerDoNotExist => {
+            "Access rules must refer to layers of the architecture"
+        }
+        _ => "Architecture rule",
+    }
+}
+
+/// Describes the `violation` in a single sentence, see `ViolationRecord::message`, including the reason of the violated rule
+pub fn violation_message(violation: &ViolationRecord) -> String {
+    match violation.reason() {
+        Some(reason) => format!("{} (reason: {})", violation.message(), reason),
+        None => violation.message(),
+    }
+}
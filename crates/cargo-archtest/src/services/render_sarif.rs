@@ -0,0 +1,163 @@
+use arch_test_core::{
+    ObjectRecord, RuleViolationType, Severity, SourceSpan, UseRecord, ViolationRecord,
+};
+use serde_json::{json, Value};
+
+use crate::domain_values::CrateReport;
+use crate::services::{rule_ids, violation_message, violation_type_description};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const INFORMATION_URI: &str = "https://github.com/Geigerkind/arch_test";
+
+/// Renders the reports of all crates as a SARIF 2.1.0 log with one run per crate.
+/// Every access rule of a crate is a `rule` of its run, violations that are not caused by an access rule,
+/// e.g. unused suppressions, are assigned to a rule named after their `RuleViolationType`.
+/// A result points to the accessor of the violation or its location, if it is not caused by a use.
+/// The accessed object and the further hops of a cycle are related locations.
+pub fn render_sarif(crate_reports: &[CrateReport]) -> String {
+    let runs: Vec<Value> = crate_reports.iter().map(sarif_run).collect();
+    serde_json::to_string_pretty(&json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": runs,
+    }))
+    .unwrap()
+}
+
+fn sarif_run(crate_report: &CrateReport) -> Value {
+    let report = &crate_report.report;
+    let mut rule_ids = rule_ids(report.rules());
+    let mut rules: Vec<Value> = report
+        .rules()
+        .iter()
+        .zip(rule_ids.iter())
+        .map(|(rule, rule_id)| {
+            json!({
+                "id": rule_id,
+                "name": rule.rule().kind(),
+                "shortDescription": { "text": rule.description() },
+                "defaultConfiguration": { "level": level(rule.severity()) },
+                "properties": { "parameters": rule.rule().parameters() },
+            })
+        })
+        .collect();
+
+    let mut results = Vec::new();
+    for violation in report.violations() {
+        let rule_index = match violation.rule_index() {
+            Some(rule_index) => rule_index,
+            None => {
+                let rule_id = format!("{:?}", violation.violation_type());
+                match rule_ids.iter().position(|id| *id == rule_id) {
+                    Some(rule_index) => rule_index,
+                    None => {
+                        rules.push(json!({
+                            "id": rule_id,
+                            "name": rule_id,
+                            "shortDescription": { "text": violation_type_description(violation.violation_type()) },
+                        }));
+                        rule_ids.push(rule_id);
+                        rule_ids.len() - 1
+                    }
+                }
+            }
+        };
+        results.push(json!({
+            "ruleId": rule_ids[rule_index],
+            "ruleIndex": rule_index,
+            "level": level(violation.severity()),
+            "message": { "text": violation_message(violation) },
+            "locations": result_locations(violation),
+            "relatedLocations": related_locations(violation),
+        }));
+    }
+
+    json!({
+        "tool": {
+            "driver": {
+                "name": "cargo-archtest",
+                "version": env!("CARGO_PKG_VERSION"),
+                "informationUri": INFORMATION_URI,
+                "rules": rules,
+            }
+        },
+        "results": results,
+    })
+}
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Off => "none",
+    }
+}
+
+fn result_locations(violation: &ViolationRecord) -> Vec<Value> {
+    match violation.uses().first() {
+        Some(use_record) => location(use_record.accessor()).into_iter().collect(),
+        None => violation
+            .locations()
+            .iter()
+            .map(|location| physical_location(location.file(), location.span()))
+            .collect(),
+    }
+}
+
+fn related_locations(violation: &ViolationRecord) -> Vec<Value> {
+    let related_objects: Vec<(&ObjectRecord, String)> = match violation.violation_type() {
+        RuleViolationType::Cycle => violation
+            .uses()
+            .iter()
+            .skip(1)
+            .map(UseRecord::accessor)
+            .map(|accessor| {
+                (
+                    accessor,
+                    format!("Continues the cycle in `{}`", accessor.module_path()),
+                )
+            })
+            .collect(),
+        RuleViolationType::SingleLocation => violation
+            .uses()
+            .iter()
+            .map(UseRecord::accessed)
+            .map(|accessed| (accessed, format!("Accessed `{}`", accessed.object_path())))
+            .collect(),
+        _ => Vec::new(),
+    };
+    related_objects
+        .into_iter()
+        .filter_map(|(object, text)| {
+            location(object).map(|mut location| {
+                location["message"] = json!({ "text": text });
+                location
+            })
+        })
+        .enumerate()
+        .map(|(id, mut location)| {
+            location["id"] = json!(id);
+            location
+        })
+        .collect()
+}
+
+/// Returns the physical location of the `object`, `None` for external objects
+fn location(object: &ObjectRecord) -> Option<Value> {
+    let file = object.file()?;
+    Some(physical_location(file, object.span()))
+}
+
+fn physical_location(file: &str, span: Option<SourceSpan>) -> Value {
+    let uri = file.trim_start_matches("./").replace('\\', "/");
+    let mut physical_location = json!({ "artifactLocation": { "uri": uri } });
+    if let Some(span) = span {
+        physical_location["region"] = json!({
+            "startLine": span.start_line(),
+            "startColumn": span.start_column(),
+            "endLine": span.end_line(),
+            "endColumn": span.end_column(),
+        });
+    }
+    json!({ "physicalLocation": physical_location })
+}
@@ -0,0 +1,4 @@
+mod domain;
+mod infrastructure;
+
+fn main() {}
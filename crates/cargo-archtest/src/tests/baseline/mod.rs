@@ -0,0 +1,156 @@
+use std::path::Path;
+
+use arch_test_core::access_rules::NoModuleCyclicDependencies;
+use arch_test_core::{RuleViolation, RuleViolationType};
+
+use crate::domain_values::{BaselineEntry, BaselineMode, CheckOptions};
+use crate::services::{check_architecture, read_baseline};
+
+const LEGACY_ARCHITECTURE: &str = "src/tests/baseline/legacy_architecture";
+
+#[test]
+fn without_baseline() {
+    assert!(!check_architecture(LEGACY_ARCHITECTURE, &CheckOptions::default(), None).valid);
+}
+
+#[test]
+fn filter_baseline() {
+    let baseline_mode = BaselineMode::Filter {
+        file_name: "archtest_baseline.json".to_owned(),
+        strict: true,
+    };
+    assert!(
+        check_architecture(
+            LEGACY_ARCHITECTURE,
+            &CheckOptions {
+                baseline_mode: Some(baseline_mode),
+                ..CheckOptions::default()
+            },
+            None
+        )
+        .valid
+    );
+}
+
+#[test]
+fn outdated_baseline() {
+    let baseline_mode = BaselineMode::Filter {
+        file_name: "outdated_baseline.json".to_owned(),
+        strict: false,
+    };
+    assert!(
+        check_architecture(
+            LEGACY_ARCHITECTURE,
+            &CheckOptions {
+                baseline_mode: Some(baseline_mode),
+                ..CheckOptions::default()
+            },
+            None
+        )
+        .valid
+    );
+    let baseline_mode = BaselineMode::Filter {
+        file_name: "outdated_baseline.json".to_owned(),
+        strict: true,
+    };
+    assert!(
+        !check_architecture(
+            LEGACY_ARCHITECTURE,
+            &CheckOptions {
+                baseline_mode: Some(baseline_mode),
+                ..CheckOptions::default()
+            },
+            None
+        )
+        .valid
+    );
+}
+
+#[test]
+fn missing_baseline() {
+    let baseline_mode = BaselineMode::Filter {
+        file_name: "missing_baseline.json".to_owned(),
+        strict: false,
+    };
+    assert!(
+        !check_architecture(
+            LEGACY_ARCHITECTURE,
+            &CheckOptions {
+                baseline_mode: Some(baseline_mode),
+                ..CheckOptions::default()
+            },
+            None
+        )
+        .valid
+    );
+}
+
+#[test]
+fn write_baseline() {
+    let baseline_path = std::env::temp_dir().join("cargo_archtest_write_baseline.json");
+    let baseline_mode = BaselineMode::Write {
+        file_name: baseline_path.to_str().unwrap().to_owned(),
+    };
+    assert!(
+        check_architecture(
+            LEGACY_ARCHITECTURE,
+            &CheckOptions {
+                baseline_mode: Some(baseline_mode),
+                ..CheckOptions::default()
+            },
+            None
+        )
+        .valid
+    );
+    assert_eq!(
+        read_baseline(&baseline_path).unwrap(),
+        read_baseline(&Path::new(LEGACY_ARCHITECTURE).join("archtest_baseline.json")).unwrap()
+    );
+    std::fs::remove_file(baseline_path).unwrap();
+}
+
+#[test]
+fn baseline_mode_from_arguments() {
+    assert!(BaselineMode::from_arguments(None, false, true).is_none());
+    assert_eq!(
+        BaselineMode::from_arguments(None, true, false)
+            .unwrap()
+            .file_name(),
+        BaselineMode::DEFAULT_FILE_NAME
+    );
+    assert!(matches!(
+        BaselineMode::from_arguments(Some("baseline.json".to_owned()), false, true),
+        Some(BaselineMode::Filter { strict: true, .. })
+    ));
+}
+
+#[test]
+fn legacy_entries() {
+    let baseline =
+        read_baseline(&Path::new(LEGACY_ARCHITECTURE).join("archtest_baseline.json")).unwrap();
+    assert_eq!(
+        baseline,
+        vec![BaselineEntry::Use {
+            rule: "MayNotAccess".to_owned(),
+            accessor: "crate::domain".to_owned(),
+            accessed: "crate::infrastructure::Database".to_owned(),
+        }]
+    );
+}
+
+#[test]
+fn cycle_entry() {
+    let violation = RuleViolation::new(
+        RuleViolationType::Cycle,
+        Box::new(NoModuleCyclicDependencies),
+        vec![],
+    )
+    .with_cycle_members(vec!["crate::b".to_owned(), "crate::a".to_owned()]);
+    assert_eq!(
+        BaselineEntry::from(&violation),
+        BaselineEntry::Cycle {
+            rule: "NoModuleCyclicDependencies".to_owned(),
+            members: vec!["crate::a".to_owned(), "crate::b".to_owned()],
+        }
+    );
+}
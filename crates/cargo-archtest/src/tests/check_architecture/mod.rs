@@ -1,6 +1,84 @@
-use crate::services::check_architecture;
+use crate::domain_values::CheckOptions;
+use crate::services::{check_architecture, create_cfg_set};
 
 #[test]
 fn run_check_architecture() {
-    check_architecture("src/tests/check_architecture/test_architecture", true);
+    assert!(
+        check_architecture(
+            "src/tests/check_architecture/test_architecture",
+            &CheckOptions {
+                check_for_complete_layer_specification: true,
+                ..CheckOptions::default()
+            },
+            None
+        )
+        .valid
+    );
+}
+
+#[test]
+fn run_check_architecture_with_cfg() {
+    let cfg_set = create_cfg_set(true, &[], &[]).unwrap();
+    assert!(
+        check_architecture(
+            "src/tests/check_architecture/test_architecture",
+            &CheckOptions {
+                check_for_complete_layer_specification: true,
+                cfg_set: Some(cfg_set),
+                ..CheckOptions::default()
+            },
+            None
+        )
+        .valid
+    );
+}
+
+#[test]
+fn run_check_architecture_with_warnings() {
+    let outcome = check_architecture(
+        "src/tests/check_architecture/warning_architecture",
+        &CheckOptions::default(),
+        None,
+    );
+    assert!(outcome.valid);
+    assert_eq!(outcome.warning_count, 1);
+}
+
+#[test]
+fn run_check_architecture_deny_warnings() {
+    assert!(
+        !check_architecture(
+            "src/tests/check_architecture/warning_architecture",
+            &CheckOptions {
+                deny_warnings: true,
+                ..CheckOptions::default()
+            },
+            None
+        )
+        .valid
+    );
+}
+
+#[test]
+fn run_check_architecture_with_invalid_selector() {
+    assert!(
+        !check_architecture(
+            "src/tests/check_architecture/invalid_selector_architecture",
+            &CheckOptions::default(),
+            None
+        )
+        .valid
+    );
+}
+
+#[test]
+fn run_check_architecture_with_invalid_visibility() {
+    assert!(
+        !check_architecture(
+            "src/tests/check_architecture/invalid_visibility_architecture",
+            &CheckOptions::default(),
+            None
+        )
+        .valid
+    );
 }
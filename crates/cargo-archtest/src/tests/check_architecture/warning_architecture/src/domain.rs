@@ -0,0 +1,5 @@
+use crate::infrastructure::Database;
+
+pub struct Order {
+    database: Database,
+}
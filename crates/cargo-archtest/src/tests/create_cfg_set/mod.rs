@@ -0,0 +1,17 @@
+use crate::services::create_cfg_set;
+
+#[test]
+fn create() {
+    assert!(create_cfg_set(false, &[], &[]).is_none());
+    assert!(create_cfg_set(true, &[], &[]).is_some());
+
+    let cfg_set = create_cfg_set(
+        false,
+        &["postgres".to_owned()],
+        &["test".to_owned(), "target_env=\"gnu\"".to_owned()],
+    )
+    .unwrap();
+    assert!(cfg_set.contains_key_value("feature", "postgres"));
+    assert!(cfg_set.contains_option("test"));
+    assert!(cfg_set.contains_key_value("target_env", "gnu"));
+}
@@ -1,2 +1,5 @@
+mod baseline;
 mod check_architecture;
+mod create_cfg_set;
 mod parse_specification;
+mod render_report;
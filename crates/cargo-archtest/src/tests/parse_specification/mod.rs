@@ -1,5 +1,6 @@
 use std::path::Path;
 
+use crate::domain_values::Failure;
 use crate::services::parse_specification;
 
 #[test]
@@ -8,3 +9,23 @@ fn parse() {
         parse_specification(Path::new("src/tests/parse_specification/architecture.json")).unwrap();
     // Not exactly sure how to assert it, but the important stuff is that most lines are run through and none panic
 }
+
+#[test]
+fn parse_invalid_selector() {
+    assert!(matches!(
+        parse_specification(Path::new(
+            "src/tests/parse_specification/invalid_selector.json"
+        )),
+        Err(Failure::SpecificationCouldNotBeParsed(_))
+    ));
+}
+
+#[test]
+fn parse_invalid_visibility() {
+    assert!(matches!(
+        parse_specification(Path::new(
+            "src/tests/parse_specification/invalid_visibility.json"
+        )),
+        Err(Failure::SpecificationCouldNotBeParsed(_))
+    ));
+}
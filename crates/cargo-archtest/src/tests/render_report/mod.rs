@@ -0,0 +1,106 @@
+use serde_json::Value;
+
+use crate::domain_values::{CheckOptions, CrateReport};
+use crate::services::{check_architecture, render_json, render_junit, render_sarif};
+
+fn warning_architecture_reports() -> Vec<CrateReport> {
+    let mut crate_reports = Vec::new();
+    assert!(
+        !check_architecture(
+            "src/tests/check_architecture/warning_architecture",
+            &CheckOptions {
+                deny_warnings: true,
+                ..CheckOptions::default()
+            },
+            Some(&mut crate_reports)
+        )
+        .valid
+    );
+    crate_reports
+}
+
+#[test]
+fn render_json_report() {
+    let crate_reports = warning_architecture_reports();
+    let json: Value = serde_json::from_str(&render_json(&crate_reports)).unwrap();
+    assert_eq!(json["valid"], false);
+    let crate_report = &json["crates"][0];
+    assert_eq!(
+        crate_report["directory"],
+        "src/tests/check_architecture/warning_architecture"
+    );
+    assert_eq!(crate_report["error_count"], 0);
+    assert_eq!(crate_report["warning_count"], 1);
+    assert_eq!(crate_report["rules"].as_array().unwrap().len(), 3);
+
+    let violation = &crate_report["violations"][0];
+    assert_eq!(violation["rule"]["kind"], "MayNotAccess");
+    assert_eq!(violation["rule"]["parameters"]["accessor"], "domain");
+    assert_eq!(violation["violation_type"], "SingleLocation");
+    assert_eq!(violation["severity"], "warning");
+    let accessor = &violation["uses"][0]["accessor"];
+    assert_eq!(
+        accessor["file"],
+        "src/tests/check_architecture/warning_architecture/src/domain.rs"
+    );
+    assert_eq!(accessor["module_path"], "crate::domain");
+    assert_eq!(accessor["span"]["start_line"], 1);
+    let accessed = &violation["uses"][0]["accessed"];
+    assert_eq!(accessed["object_name"], "Database");
+    assert_eq!(accessed["object_kind"], "Struct");
+}
+
+#[test]
+fn render_sarif_report() {
+    let crate_reports = warning_architecture_reports();
+    let sarif: Value = serde_json::from_str(&render_sarif(&crate_reports)).unwrap();
+    assert_eq!(sarif["version"], "2.1.0");
+    let run = &sarif["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "cargo-archtest");
+    let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+    assert_eq!(rules.len(), 3);
+    assert_eq!(rules[0]["id"], "MayNotAccess");
+    assert_eq!(rules[0]["defaultConfiguration"]["level"], "warning");
+    assert_eq!(rules[2]["defaultConfiguration"]["level"], "none");
+
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["ruleId"], "MayNotAccess");
+    assert_eq!(results[0]["ruleIndex"], 0);
+    assert_eq!(results[0]["level"], "warning");
+    assert_eq!(
+        results[0]["message"]["text"],
+        "`crate::domain` accesses `crate::infrastructure::Database`"
+    );
+    let location = &results[0]["locations"][0]["physicalLocation"];
+    assert_eq!(
+        location["artifactLocation"]["uri"],
+        "src/tests/check_architecture/warning_architecture/src/domain.rs"
+    );
+    assert_eq!(location["region"]["startLine"], 1);
+    let related_location = &results[0]["relatedLocations"][0]["physicalLocation"];
+    assert_eq!(
+        related_location["artifactLocation"]["uri"],
+        "src/tests/check_architecture/warning_architecture/src/infrastructure.rs"
+    );
+    assert_eq!(related_location["region"]["startLine"], 1);
+}
+
+#[test]
+fn render_junit_report() {
+    let crate_reports = warning_architecture_reports();
+    let junit = render_junit(&crate_reports, false);
+    assert!(junit.contains("<testsuites name=\"cargo-archtest\" tests=\"3\" failures=\"0\">"));
+    assert!(junit.contains(
+        "<testsuite name=\"src/tests/check_architecture/warning_architecture\" tests=\"3\" failures=\"0\" skipped=\"1\">"
+    ));
+    assert!(junit.contains("name=\"NoModuleCyclicDependencies\"/>"));
+    assert!(junit.contains("name=\"NoParentAccess\">\n      <skipped/>"));
+    assert!(junit.contains("name=\"MayNotAccess\">\n      <system-out>warning[MayNotAccess]: `crate::domain` accesses `crate::infrastructure::Database`\n"));
+
+    let junit = render_junit(&crate_reports, true);
+    assert!(junit.contains("<testsuites name=\"cargo-archtest\" tests=\"3\" failures=\"1\">"));
+    assert!(junit.contains(
+        "<failure message=\"1 violation(s)\" type=\"MayNotAccess\">warning[MayNotAccess]: `crate::domain` accesses `crate::infrastructure::Database`\n --&gt; src/tests/check_architecture/warning_architecture/src/domain.rs:1:5\n"
+    ));
+}
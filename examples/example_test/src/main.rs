@@ -1,12 +1,71 @@
+fn main() {}
+
 #[cfg(test)]
 mod test {
     extern crate arch_test_core;
 
+    use std::collections::HashSet;
+    use std::fmt::{Display, Formatter};
+
     use arch_test_core::access_rules::{
         MayNotAccess, MayNotBeAccessedBy, MayOnlyAccess, MayOnlyBeAccessedBy,
         NoLayerCyclicDependencies, NoModuleCyclicDependencies, NoParentAccess,
     };
-    use arch_test_core::{hash_set, Architecture, ModuleTree};
+    use arch_test_core::{
+        has_parent_matching_name, hash_set, AccessRule, Architecture, ModuleTree, RuleViolation,
+        RuleViolationType, ViolatedRule,
+    };
+
+    /// Forbids that modules in `layer` access any module that is placed directly in the root module
+    #[derive(Debug, Clone)]
+    struct NoRootAccessFrom {
+        layer: String,
+    }
+
+    impl Display for NoRootAccessFrom {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "modules in `{}` may not access the root module",
+                self.layer
+            )
+        }
+    }
+
+    impl ViolatedRule for NoRootAccessFrom {
+        fn name(&self) -> &'static str {
+            "NoRootAccessFrom"
+        }
+    }
+
+    impl AccessRule for NoRootAccessFrom {
+        fn check(&self, module_tree: &ModuleTree) -> Result<(), RuleViolation<'_>> {
+            let tree = module_tree.tree();
+            for node in tree.iter().filter(|node| {
+                node.module_name() == &self.layer
+                    || has_parent_matching_name(&hash_set![self.layer.clone()], node.index(), tree)
+            }) {
+                if let Some(use_relation) = node
+                    .use_relations(tree, module_tree.possible_uses(), false)
+                    .into_iter()
+                    .find(|use_relation| {
+                        tree[use_relation.used_object().node_index()].parent_index() == Some(0)
+                    })
+                {
+                    return Err(RuleViolation::new(
+                        RuleViolationType::SingleLocation,
+                        Box::new(self.clone()),
+                        vec![use_relation],
+                    ));
+                }
+            }
+            Ok(())
+        }
+
+        fn validate(&self, layer_names: &HashSet<String>) -> bool {
+            layer_names.contains(&self.layer)
+        }
+    }
 
     #[test]
     fn test_architecture() {
@@ -47,6 +106,17 @@ mod test {
         assert!(architecture.validate_access_rules().is_ok());
         assert!(architecture.check_access_rules(&module_tree).is_ok());
     }
-}
 
-fn main() {}
+    #[test]
+    fn test_custom_access_rule() {
+        let architecture =
+            Architecture::new(hash_set!["file_1".to_owned()]).with_access_rule(NoRootAccessFrom {
+                layer: "file_1".to_owned(),
+            });
+        let module_tree = ModuleTree::new(
+            "../../crates/arch_test_core/src/analyzer/tests/access_rules/may_access/main.rs",
+        );
+        assert!(architecture.validate_access_rules().is_ok());
+        assert!(architecture.check_access_rules(&module_tree).is_err());
+    }
+}